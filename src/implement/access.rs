@@ -0,0 +1,929 @@
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::{
+    parse_quote, Error, Expr, GenericArgument, PathArguments, Receiver, Result, ReturnType, Type,
+};
+
+use crate::implement::{is_rcv_mut, is_ret_future, quote_target, replace_self};
+use crate::model::{Access, Aggregate, Fallback, Method, Payload, Poison, RcvKind};
+
+impl Access {
+    pub(super) fn outer_method(&self, meth: &Method) -> Result<Method> {
+        let mut outer = meth.clone();
+
+        if self.is_interior() {
+            outer.rcv = shared_rcv(meth);
+            outer.rcv_kind = RcvKind::Ref;
+        }
+
+        if let Some(err) = self.quote_error(meth) {
+            let output = match &meth.ret {
+                ReturnType::Default => quote!(()),
+                ReturnType::Type(_, ty) => quote!(#ty),
+            };
+
+            outer.ret = parse_quote!(-> ::core::result::Result<#output, #err>);
+        }
+
+        match (self, &meth.ret) {
+            (Access::Optional(None), ReturnType::Type(_, ty)) => {
+                outer.ret = parse_quote!(-> ::core::option::Option<#ty>);
+            }
+            (Access::Each(Some(Aggregate::Vec)), ReturnType::Type(_, ty)) => {
+                outer.ret = parse_quote!(-> ::std::vec::Vec<#ty>);
+            }
+            (Access::Each(Some(Aggregate::Result)), ReturnType::Type(_, ty)) => {
+                if let Some(collected) = quote_collected_result(ty) {
+                    outer.ret = parse_quote!(-> #collected);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(outer)
+    }
+
+    pub(super) fn quote_place(&self, target: &Expr, meth: &Method) -> Result<TokenStream> {
+        if !matches!(self, Access::Direct | Access::Pin) {
+            require_plain_rcv(meth)?;
+        }
+        let expr = target;
+        let target = quote_target(target);
+
+        match self {
+            Access::Direct => quote_rcv_place(target, meth),
+            Access::Pin => quote_pin_place(expr, target, meth),
+            Access::Borrow | Access::TryBorrow => {
+                require_ref_rcv(meth, "RefCell")?;
+
+                let borrow = match (self, is_rcv_mut(meth)) {
+                    (Access::TryBorrow, true) => quote!(try_borrow_mut()?),
+                    (Access::TryBorrow, false) => quote!(try_borrow()?),
+                    (_, true) => quote!(borrow_mut()),
+                    (_, false) => quote!(borrow()),
+                };
+
+                Ok(quote_guard(target, borrow, meth))
+            }
+            Access::Lock(poison) | Access::RwLock(poison) => {
+                require_ref_rcv(meth, "lock")?;
+                require_owned_ret(meth)?;
+
+                let acquire = match (self, is_rcv_mut(meth)) {
+                    (Access::Lock(_), _) => quote!(lock()),
+                    (_, true) => quote!(write()),
+                    (_, false) => quote!(read()),
+                };
+                let unwrap = poison.quote_unwrap();
+
+                Ok(quote_guard(target, quote!(#acquire #unwrap), meth))
+            }
+            Access::Optional(_) => {
+                require_awaited(meth, "optional")?;
+
+                Ok(quote_binding_place(meth))
+            }
+            Access::Variants(_) => {
+                require_awaited(meth, "enum")?;
+
+                Ok(quote_binding_place(meth))
+            }
+            Access::Each(aggregate) => {
+                require_awaited(meth, "fan-out")?;
+                if let ReturnType::Type(..) = meth.ret {
+                    require_aggregate(meth, aggregate)?;
+                }
+                if *aggregate == Some(Aggregate::Result) {
+                    require_result_ok(meth)?;
+                }
+
+                Ok(quote_binding_place(meth))
+            }
+        }
+    }
+
+    pub(super) fn quote_body(
+        &self,
+        target: &Expr,
+        meth: &Method,
+        call: TokenStream,
+    ) -> TokenStream {
+        match self {
+            Access::Optional(fallback) => {
+                let target = quote_target(target);
+                let inner = binding();
+                let option = match meth.rcv_kind {
+                    RcvKind::RefMut => quote!(#target.as_mut()),
+                    RcvKind::Ref => quote!(#target.as_ref()),
+                    _ => target,
+                };
+
+                let (some, none) = match fallback {
+                    None if matches!(meth.ret, ReturnType::Default) => (call, quote!(())),
+                    None => (
+                        quote!(::core::option::Option::Some(#call)),
+                        quote!(::core::option::Option::None),
+                    ),
+                    Some(Fallback::Default) => (call, quote!(::core::default::Default::default())),
+                    Some(Fallback::Panic(msg)) => (call, quote!(::core::panic!("{}", #msg))),
+                };
+
+                quote!(match #option {
+                    ::core::option::Option::Some(#inner) => #some,
+                    ::core::option::Option::None => #none,
+                })
+            }
+            Access::Variants(payloads) => {
+                let target = quote_target(target);
+                let arms = payloads.iter().map(Payload::quote_pattern);
+
+                quote!(match #target { #(#arms => #call,) * })
+            }
+            Access::Each(aggregate) => {
+                let target = quote_target(target);
+                let inner = binding();
+                let iter = match meth.rcv_kind {
+                    RcvKind::RefMut => quote!(#target.iter_mut()),
+                    RcvKind::Ref => quote!(#target.iter()),
+                    _ => quote!(#target.into_iter()),
+                };
+
+                match (aggregate, &meth.ret) {
+                    (None, _) | (_, ReturnType::Default) => quote!(for #inner in #iter { #call }),
+                    (Some(Aggregate::Vec), _) => quote!(#iter.map(|#inner| #call).collect()),
+                    (Some(Aggregate::All), _) => quote!(#iter.all(|#inner| #call)),
+                    (Some(Aggregate::Any), _) => quote!(#iter.any(|#inner| #call)),
+                    (Some(Aggregate::First), _) => quote!(#iter.find_map(|#inner| #call)),
+                    (Some(Aggregate::Sum), _) => quote!(#iter.map(|#inner| #call).sum()),
+                    (Some(Aggregate::Result), ReturnType::Type(_, ty)) if is_unit_result(ty) => {
+                        quote!(#iter.try_for_each(|#inner| #call))
+                    }
+                    (Some(Aggregate::Result), _) => quote!(#iter.map(|#inner| #call).collect()),
+                }
+            }
+            _ if self.is_fallible() => quote!(::core::result::Result::Ok(#call)),
+            _ => call,
+        }
+    }
+
+    pub(super) fn is_signature_preserved(&self, meth: &Method) -> bool {
+        let is_wrapped = matches!(
+            self,
+            Access::Optional(None) | Access::Each(Some(Aggregate::Vec))
+        ) && !matches!(meth.ret, ReturnType::Default);
+        let is_collected = *self == Access::Each(Some(Aggregate::Result))
+            && matches!(&meth.ret, ReturnType::Type(_, ty) if quote_collected_result(ty).is_some());
+
+        !self.is_fallible() && !is_wrapped && !is_collected
+    }
+
+    fn is_interior(&self) -> bool {
+        matches!(
+            self,
+            Access::Borrow | Access::TryBorrow | Access::Lock(_) | Access::RwLock(_)
+        )
+    }
+
+    fn is_fallible(&self) -> bool {
+        matches!(
+            self,
+            Access::TryBorrow | Access::Lock(Poison::Result) | Access::RwLock(Poison::Result)
+        )
+    }
+
+    fn quote_error(&self, meth: &Method) -> Option<TokenStream> {
+        match self {
+            Access::TryBorrow if is_rcv_mut(meth) => Some(quote!(::core::cell::BorrowMutError)),
+            Access::TryBorrow => Some(quote!(::core::cell::BorrowError)),
+            Access::Lock(Poison::Result) | Access::RwLock(Poison::Result) => {
+                Some(quote!(::std::sync::PoisonError<()>))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Payload {
+    fn quote_pattern(&self) -> TokenStream {
+        let variant = &self.variant;
+        let inner = binding();
+
+        match &self.field {
+            Some(field) => quote!(Self::#variant { #field: #inner }),
+            None => quote!(Self::#variant(#inner)),
+        }
+    }
+}
+
+impl Poison {
+    fn quote_unwrap(&self) -> TokenStream {
+        match self {
+            Poison::Panic => quote!(.unwrap()),
+            Poison::Recover => quote!(.unwrap_or_else(::std::sync::PoisonError::into_inner)),
+            Poison::Result => quote!(.map_err(|_| ::std::sync::PoisonError::new(()))?),
+        }
+    }
+}
+
+fn quote_rcv_place(target: TokenStream, meth: &Method) -> Result<TokenStream> {
+    match meth.rcv_kind {
+        RcvKind::Value | RcvKind::Ref | RcvKind::RefMut => Ok(target),
+        RcvKind::Boxed => {
+            let unboxed = Group::new(Delimiter::Parenthesis, quote!(*self));
+            Ok(replace_self(target, &TokenTree::Group(unboxed)))
+        }
+        RcvKind::Shared => {
+            if is_ret_future(meth) {
+                return Err(Error::new_spanned(
+                    &meth.rcv,
+                    "futures cannot borrow from 'Rc<Self>' or 'Arc<Self>' receivers, await them instead",
+                ));
+            }
+
+            Ok(target)
+        }
+        RcvKind::PinRef | RcvKind::PinMut => Err(Error::new_spanned(
+            &meth.rcv,
+            "pinned receivers need an 'unsafe pin' target such as 'to unsafe pin self.field', asserting the field is structurally pinned",
+        )),
+    }
+}
+
+fn quote_pin_place(expr: &Expr, target: TokenStream, meth: &Method) -> Result<TokenStream> {
+    if !matches!(meth.rcv_kind, RcvKind::PinRef | RcvKind::PinMut) {
+        return Err(Error::new_spanned(
+            &meth.rcv,
+            "'unsafe pin' targets can only forward methods taking 'self: Pin<&Self>' or 'self: Pin<&mut Self>'",
+        ));
+    }
+    if !is_field_of_self(expr) {
+        return Err(Error::new_spanned(
+            expr,
+            "'unsafe pin' targets must be a field of self such as 'self.field'",
+        ));
+    }
+
+    let inner = binding();
+    let projected = replace_self(target, &TokenTree::Ident(inner.clone()));
+
+    Ok(if meth.rcv_kind == RcvKind::PinMut {
+        quote!(unsafe { ::core::pin::Pin::map_unchecked_mut(self, |#inner| &mut #projected) })
+    } else {
+        quote!(unsafe { ::core::pin::Pin::map_unchecked(self, |#inner| &#projected) })
+    })
+}
+
+fn is_field_of_self(target: &Expr) -> bool {
+    match target {
+        Expr::Field(x) => matches!(x.base.as_ref(), Expr::Path(base) if base.path.is_ident("self")),
+        _ => false,
+    }
+}
+
+fn require_plain_rcv(meth: &Method) -> Result<()> {
+    if !matches!(
+        meth.rcv_kind,
+        RcvKind::Value | RcvKind::Ref | RcvKind::RefMut
+    ) {
+        return Err(Error::new_spanned(
+            &meth.rcv,
+            format!(
+                "receivers of type '{}' can only be forwarded to targets without modifiers",
+                meth.rcv.ty.to_token_stream()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn require_ref_rcv(meth: &Method, kind: &str) -> Result<()> {
+    if !matches!(meth.rcv_kind, RcvKind::Ref | RcvKind::RefMut) {
+        return Err(Error::new_spanned(
+            &meth.rcv,
+            format!(
+                "{} targets can only forward methods taking '&self' or '&mut self'",
+                kind
+            ),
+        ));
+    }
+
+    require_awaited(meth, kind)
+}
+
+fn require_awaited(meth: &Method, kind: &str) -> Result<()> {
+    if is_ret_future(meth) {
+        return Err(Error::new_spanned(
+            &meth.ident,
+            format!(
+                "{} targets cannot return futures without awaiting them",
+                kind
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn require_aggregate(meth: &Method, aggregate: &Option<Aggregate>) -> Result<()> {
+    if aggregate.is_none() {
+        return Err(Error::new_spanned(
+            &meth.ret,
+            "fan-out targets need an aggregator for methods returning values, such as 'each(vec)'",
+        ));
+    }
+    if meth.asyncness.is_some() {
+        return Err(Error::new_spanned(
+            &meth.ident,
+            "fan-out targets cannot aggregate the results of async methods",
+        ));
+    }
+
+    Ok(())
+}
+
+fn require_result_ok(meth: &Method) -> Result<()> {
+    match &meth.ret {
+        ReturnType::Type(_, ty) if result_ok_type(ty).is_some() => Ok(()),
+        ret => Err(Error::new_spanned(
+            ret,
+            "result aggregation requires the method to return a 'Result<T, E>'",
+        )),
+    }
+}
+
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(x) = ty else {
+        return None;
+    };
+    let segment = x.path.segments.last().filter(|x| x.ident == "Result")?;
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return None;
+    };
+
+    match generics.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+fn is_unit_result(ty: &Type) -> bool {
+    matches!(result_ok_type(ty), Some(Type::Tuple(x)) if x.elems.is_empty())
+}
+
+fn quote_collected_result(ty: &Type) -> Option<Type> {
+    if result_ok_type(ty).is_none() || is_unit_result(ty) {
+        return None;
+    }
+
+    let mut collected = ty.clone();
+    let Type::Path(x) = &mut collected else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(generics) = &mut x.path.segments.last_mut()?.arguments else {
+        return None;
+    };
+    let Some(GenericArgument::Type(ok)) = generics.args.first_mut() else {
+        return None;
+    };
+    *ok = parse_quote!(::std::vec::Vec<#ok>);
+
+    Some(collected)
+}
+
+fn binding() -> Ident {
+    Ident::new("inner", Span::mixed_site())
+}
+
+fn quote_binding_place(meth: &Method) -> TokenStream {
+    let inner = binding();
+
+    if matches!(meth.rcv_kind, RcvKind::Ref | RcvKind::RefMut) {
+        quote!((*#inner))
+    } else {
+        quote!(#inner)
+    }
+}
+
+fn shared_rcv(meth: &Method) -> Receiver {
+    match meth.rcv.ty.as_ref() {
+        Type::Reference(x) => {
+            let lifetime = &x.lifetime;
+            parse_quote!(&#lifetime self)
+        }
+        _ => meth.rcv.clone(),
+    }
+}
+
+fn quote_guard(target: TokenStream, guard: TokenStream, meth: &Method) -> TokenStream {
+    // Values borrowed from the guard through lifetimes elided in paths, such as 'Iter<u8>',
+    // are only caught by the borrow checker, which then points at the declared return type
+    let span = meth.ret.span();
+    let guard = respan(guard, span);
+
+    quote_spanned!(span=> (*#target.#guard))
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(x) => {
+                let mut group = Group::new(x.delimiter(), respan(x.stream(), span));
+                group.set_span(span);
+                TokenTree::Group(group)
+            }
+            mut tt => {
+                tt.set_span(span);
+                tt
+            }
+        })
+        .collect()
+}
+
+fn require_owned_ret(meth: &Method) -> Result<()> {
+    if has_borrow(meth.ret.to_token_stream()) {
+        return Err(Error::new_spanned(
+            &meth.ret,
+            "lock targets cannot return values borrowed from the lock guard, return an owned value instead",
+        ));
+    }
+
+    Ok(())
+}
+
+fn has_borrow(tokens: TokenStream) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(tt) = tokens.next() {
+        let borrowed = match tt {
+            TokenTree::Punct(x) if x.as_char() == '&' => !is_lifetime(tokens.peek()),
+            TokenTree::Punct(x) if x.as_char() == '\'' => {
+                !matches!(tokens.peek(), Some(TokenTree::Ident(x)) if x == "static")
+            }
+            TokenTree::Group(x) => has_borrow(x.stream()),
+            _ => false,
+        };
+
+        if borrowed {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn is_lifetime(tt: Option<&TokenTree>) -> bool {
+    matches!(tt, Some(TokenTree::Punct(x)) if x.as_char() == '\'')
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use test_case::test_case;
+
+    use crate::model::{
+        Access, Aggregate, Fallback, FwdDeclBuilder, MethodBuilder, Poison, RcvKind, RetMode,
+        TraitImplBuilder,
+    };
+
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Borrow).named_target("cell").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().with_arg("val: u8").ret("-> usize")
+        ),
+        quote!(fn test(&self, val: u8) -> usize { (*self.cell.borrow()).test(val) });
+        "should borrow cell for shared reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Borrow).named_target("cell").with_method(
+            MethodBuilder::default().ident("test").lifetime_ref_mut_rcv("'a").with_arg("val: u8")
+        ),
+        quote!(fn test(&'a self, val: u8) { (*self.cell.borrow_mut()).test(val) });
+        "should mutably borrow cell for mutable reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::TryBorrow).named_target("cell").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<u8>")
+        ),
+        quote!(fn test(&self) -> ::core::result::Result<Option<u8>, ::core::cell::BorrowError> {
+            ::core::result::Result::Ok((*self.cell.try_borrow()?).test())
+        });
+        "should try to borrow cell for shared reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::TryBorrow).named_target("cell").with_method(
+            MethodBuilder::default().ident("test").ref_mut_rcv()
+        ),
+        quote!(fn test(&self) -> ::core::result::Result<(), ::core::cell::BorrowMutError> {
+            ::core::result::Result::Ok((*self.cell.try_borrow_mut()?).test())
+        });
+        "should try to mutably borrow cell for mutable reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Borrow).named_target("cell").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_mut_rcv())
+        ),
+        quote!(impl Tester for Outer {
+            fn test(&mut self) { <_ as Tester>::test(&mut (*self.cell.borrow_mut())) }
+        });
+        "should borrow cell for trait forwarding"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Borrow).named_target("cell").with_method(
+            MethodBuilder::default().ident("test").rcv()
+        ),
+        quote!(::core::compile_error! {
+            "RefCell targets can only forward methods taking '&self' or '&mut self'"
+        });
+        "should reject moved receiver for cell"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::TryBorrow).named_target("cell").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_rcv())
+        ),
+        quote!(impl Tester for Outer {
+            ::core::compile_error! { "target modifier changes the signature of trait method 'test'" }
+        });
+        "should reject try borrow for trait forwarding"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Lock(Poison::Panic)).named_target("mutex").with_method(
+            MethodBuilder::default().ident("test").ref_mut_rcv().with_arg("val: u8").ret("-> Option<u8>")
+        ),
+        quote!(fn test(&self, val: u8) -> Option<u8> { (*self.mutex.lock().unwrap()).test(val) });
+        "should lock mutex and panic on poisoning"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Lock(Poison::Recover)).named_target("mutex").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<u8>")
+        ),
+        quote!(fn test(&self) -> Option<u8> {
+            (*self.mutex.lock().unwrap_or_else(::std::sync::PoisonError::into_inner)).test()
+        });
+        "should lock mutex and recover from poisoning"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Lock(Poison::Result)).named_target("mutex").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<u8>")
+        ),
+        quote!(fn test(&self) -> ::core::result::Result<Option<u8>, ::std::sync::PoisonError<()> > {
+            ::core::result::Result::Ok((*self.mutex.lock().map_err(|_| ::std::sync::PoisonError::new(()))?).test())
+        });
+        "should lock mutex and return poisoning errors"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::RwLock(Poison::Panic)).named_target("rwlock").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<u8>")
+        ),
+        quote!(fn test(&self) -> Option<u8> { (*self.rwlock.read().unwrap()).test() });
+        "should read lock for shared reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::RwLock(Poison::Panic)).named_target("rwlock").with_method(
+            MethodBuilder::default().ident("test").ref_mut_rcv().ret("-> Option<&'static str>")
+        ),
+        quote!(fn test(&self) -> Option<&'static str> { (*self.rwlock.write().unwrap()).test() });
+        "should write lock for mutable reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::RwLock(Poison::Panic)).named_target("rwlock").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<&u8>")
+        ),
+        quote!(::core::compile_error! {
+            "lock targets cannot return values borrowed from the lock guard, return an owned value instead"
+        });
+        "should reject elided borrow from lock guard"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Lock(Poison::Panic)).named_target("mutex").with_method(
+            MethodBuilder::default().ident("iter").ref_rcv().ret("-> std::slice::Iter<u8>")
+        ),
+        quote!(fn iter(&self) -> std::slice::Iter<u8> { (*self.mutex.lock().unwrap()).iter() });
+        "should leave borrow through lifetime elided in path to the borrow checker"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Lock(Poison::Panic)).named_target("mutex").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> std::slice::Iter<'_, u8>")
+        ),
+        quote!(::core::compile_error! {
+            "lock targets cannot return values borrowed from the lock guard, return an owned value instead"
+        });
+        "should reject lifetime borrow from lock guard"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Lock(Poison::Panic)).named_target("mutex").with_method(
+            MethodBuilder::default().ident("test").rcv()
+        ),
+        quote!(::core::compile_error! {
+            "lock targets can only forward methods taking '&self' or '&mut self'"
+        });
+        "should reject moved receiver for lock"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Borrow).named_target("cell").with_method(
+            MethodBuilder::default().ident("merge").ref_rcv().with_arg("other: &Self")
+        ),
+        quote!(::core::compile_error! {
+            "'Self' parameters can only be projected onto targets without modifiers"
+        });
+        "should reject self parameter projection for cell"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(None)).named_target("inner").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().with_arg("val: u8").ret("-> Option<u8>")
+        ),
+        quote!(fn test(&self, val: u8) -> ::core::option::Option<Option<u8> > {
+            match self.inner.as_ref() {
+                ::core::option::Option::Some(inner) => ::core::option::Option::Some((*inner).test(val)),
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        });
+        "should map optional target for shared reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(None)).named_target("inner").with_method(
+            MethodBuilder::default().ident("test").ref_mut_rcv().ret("-> Option<u8>")
+        ),
+        quote!(fn test(&mut self) -> ::core::option::Option<Option<u8> > {
+            match self.inner.as_mut() {
+                ::core::option::Option::Some(inner) => ::core::option::Option::Some((*inner).test()),
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        });
+        "should map optional target for mutable reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(None)).named_target("inner").with_method(
+            MethodBuilder::default().ident("test").rcv().ret("-> u8")
+        ),
+        quote!(fn test(self) -> ::core::option::Option<u8> {
+            match self.inner {
+                ::core::option::Option::Some(inner) => ::core::option::Option::Some(inner.test()),
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        });
+        "should map optional target for moved receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(None)).named_target("inner").with_method(
+            MethodBuilder::default().ident("test").rcv()
+        ),
+        quote!(fn test(self) {
+            match self.inner {
+                ::core::option::Option::Some(inner) => inner.test(),
+                ::core::option::Option::None => (),
+            }
+        });
+        "should skip unit method for empty optional target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(Some(Fallback::Default))).named_target("inner")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<u8>")),
+        quote!(fn test(&self) -> Option<u8> {
+            match self.inner.as_ref() {
+                ::core::option::Option::Some(inner) => (*inner).test(),
+                ::core::option::Option::None => ::core::default::Default::default(),
+            }
+        });
+        "should fall back to default for empty optional target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(Some(Fallback::Panic("no inner".to_string()))))
+            .named_target("inner")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<u8>")),
+        quote!(fn test(&self) -> Option<u8> {
+            match self.inner.as_ref() {
+                ::core::option::Option::Some(inner) => (*inner).test(),
+                ::core::option::Option::None => ::core::panic!("{}", "no inner"),
+            }
+        });
+        "should panic for empty optional target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(Some(Fallback::Default))).named_target("inner")
+            .with_trait(TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_rcv().ret("-> u8"))),
+        quote!(impl Tester for Outer {
+            fn test(&self) -> u8 {
+                match self.inner.as_ref() {
+                    ::core::option::Option::Some(inner) => <_ as Tester>::test(&(*inner)),
+                    ::core::option::Option::None => ::core::default::Default::default(),
+                }
+            }
+        });
+        "should forward trait method to optional target with fallback"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Optional(None)).named_target("inner").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_rcv().ret("-> u8"))
+        ),
+        quote!(impl Tester for Outer {
+            ::core::compile_error! { "target modifier changes the signature of trait method 'test'" }
+        });
+        "should reject optional target without fallback for trait forwarding"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self").with_variant("Circle", None).with_variant("Rect", Some("rect"))
+            .with_method(MethodBuilder::default().ident("area").ref_rcv().ret("-> f64")),
+        quote!(fn area(&self) -> f64 {
+            match self {
+                Self::Circle(inner) => (*inner).area(),
+                Self::Rect { rect: inner } => (*inner).area(),
+            }
+        });
+        "should dispatch to every enum variant"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self").with_variant("Circle", None).with_trait(
+            TraitImplBuilder::default().path("Shape").self_ty("Shapes")
+                .with_method(MethodBuilder::default().ident("scale").rcv().with_arg("by: f64"))
+        ),
+        quote!(impl Shape for Shapes {
+            fn scale(self, by: f64) {
+                match self { Self::Circle(inner) => <_ as Shape>::scale(inner, by), }
+            }
+        });
+        "should dispatch trait method to every enum variant"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(None)).named_target("sinks").with_method(
+            MethodBuilder::default().ident("flush").ref_mut_rcv().with_arg("force: bool")
+        ),
+        quote!(fn flush(&mut self, force: bool) {
+            for inner in self.sinks.iter_mut() { (*inner).flush(force) }
+        });
+        "should loop over fan-out target for unit method"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Vec))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("name").rcv().ret("-> String")
+        ),
+        quote!(fn name(self) -> ::std::vec::Vec<String> {
+            self.sinks.into_iter().map(|inner| inner.name()).collect()
+        });
+        "should collect fan-out results into vec"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::All))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("is_ready").ref_rcv().ret("-> bool")
+        ),
+        quote!(fn is_ready(&self) -> bool { self.sinks.iter().all(|inner| (*inner).is_ready()) });
+        "should aggregate fan-out results with all"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Any))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("is_dirty").ref_rcv().ret("-> bool")
+        ),
+        quote!(fn is_dirty(&self) -> bool { self.sinks.iter().any(|inner| (*inner).is_dirty()) });
+        "should aggregate fan-out results with any"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::First))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("find").ref_rcv().with_arg("key: u8").ret("-> Option<u8>")
+        ),
+        quote!(fn find(&self, key: u8) -> Option<u8> {
+            self.sinks.iter().find_map(|inner| (*inner).find(key))
+        });
+        "should return first fan-out result"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Sum))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("len").ref_rcv().ret("-> usize")
+        ),
+        quote!(fn len(&self) -> usize { self.sinks.iter().map(|inner| (*inner).len()).sum() });
+        "should sum fan-out results"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Result))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("sync").ref_mut_rcv().ret("-> Result<(), Error>")
+        ),
+        quote!(fn sync(&mut self) -> Result<(), Error> {
+            self.sinks.iter_mut().try_for_each(|inner| (*inner).sync())
+        });
+        "should short-circuit fan-out on first error"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Result))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("write").ref_mut_rcv().with_arg("buf: &[u8]").ret("-> io::Result<usize>")
+        ),
+        quote!(fn write(&mut self, buf: &[u8]) -> io::Result<::std::vec::Vec<usize> > {
+            self.sinks.iter_mut().map(|inner| (*inner).write(buf)).collect()
+        });
+        "should collect fan-out results until first error"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Result))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("len").ref_rcv().ret("-> usize")
+        ),
+        quote!(::core::compile_error! {
+            "result aggregation requires the method to return a 'Result<T, E>'"
+        });
+        "should require result return type for result aggregation"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(None)).named_target("sinks").with_method(
+            MethodBuilder::default().ident("len").ref_rcv().ret("-> usize")
+        ),
+        quote!(::core::compile_error! {
+            "fan-out targets need an aggregator for methods returning values, such as 'each(vec)'"
+        });
+        "should require aggregator for fan-out method returning value"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().access(Access::Each(Some(Aggregate::Vec))).named_target("sinks").with_method(
+            MethodBuilder::default().ident("len").asyncness().ref_rcv().ret("-> usize")
+        ),
+        quote!(::core::compile_error! {
+            "fan-out targets cannot aggregate the results of async methods"
+        });
+        "should reject aggregating async fan-out method"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").with_method(
+            MethodBuilder::default().ident("into_name").typed_rcv("self: Box<Self>", RcvKind::Boxed).ret("-> String")
+        ),
+        quote!(fn into_name(self: Box<Self>) -> String { (*self).inner.into_name() });
+        "should unbox boxed receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").with_method(
+            MethodBuilder::default().ident("name").typed_rcv("self: Rc<Self>", RcvKind::Shared).ret("-> String")
+                .ret_mode(RetMode::Auto)
+        ),
+        quote!(fn name(self: Rc<Self>) -> String { self.inner.name().clone() });
+        "should borrow target of reference counted receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").access(Access::Pin).with_method(
+            MethodBuilder::default().ident("poll").typed_rcv("self: Pin<&mut Self>", RcvKind::PinMut)
+                .with_arg("cx: &mut Context<'_>").ret("-> Poll<()>")
+        ),
+        quote!(fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |inner| &mut inner.inner) }.poll(cx)
+        });
+        "should project pinned mutable receiver onto target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").access(Access::Pin).with_method(
+            MethodBuilder::default().ident("peek").typed_rcv("self: Pin<&Self>", RcvKind::PinRef).ret("-> u8")
+        ),
+        quote!(fn peek(self: Pin<&Self>) -> u8 {
+            unsafe { ::core::pin::Pin::map_unchecked(self, |inner| &inner.inner) }.peek()
+        });
+        "should project pinned shared receiver onto target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").access(Access::Borrow).with_method(
+            MethodBuilder::default().ident("len").typed_rcv("self: Rc<Self>", RcvKind::Shared).ret("-> usize")
+        ),
+        quote!(::core::compile_error! {
+            "receivers of type 'Rc < Self >' can only be forwarded to targets without modifiers"
+        });
+        "should reject typed receiver with target modifier"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").with_method(
+            MethodBuilder::default().ident("poll").typed_rcv("self: Pin<&mut Self>", RcvKind::PinMut)
+        ),
+        quote!(::core::compile_error! {
+            "pinned receivers need an 'unsafe pin' target such as 'to unsafe pin self.field', asserting the field is structurally pinned"
+        });
+        "should require explicit pin projection for pinned receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("mid").named_target("inner").access(Access::Pin).with_method(
+            MethodBuilder::default().ident("poll").typed_rcv("self: Pin<&mut Self>", RcvKind::PinMut)
+        ),
+        quote!(::core::compile_error! {
+            "'unsafe pin' targets must be a field of self such as 'self.field'"
+        });
+        "should reject pin projection onto nested field"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self.inner()").access(Access::Pin).with_method(
+            MethodBuilder::default().ident("poll").typed_rcv("self: Pin<&mut Self>", RcvKind::PinMut)
+        ),
+        quote!(::core::compile_error! {
+            "'unsafe pin' targets must be a field of self such as 'self.field'"
+        });
+        "should reject pin projection onto non-field target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").access(Access::Pin).with_method(
+            MethodBuilder::default().ident("len").ref_rcv().ret("-> usize")
+        ),
+        quote!(::core::compile_error! {
+            "'unsafe pin' targets can only forward methods taking 'self: Pin<&Self>' or 'self: Pin<&mut Self>'"
+        });
+        "should require pinned receiver for pin projection"
+    )]
+    fn should_write_access_forwarding_impl(input: &FwdDeclBuilder, want: TokenStream) {
+        let decl = input.build().unwrap();
+
+        assert_eq!(decl.implement().to_string(), want.to_string())
+    }
+}
@@ -0,0 +1,1414 @@
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, Error, Expr, GenericArgument, GenericParam, Lifetime, LifetimeParam, Pat, Path,
+    PathArguments, Result, ReturnType, Token, Type, TypeParamBound, Visibility, WherePredicate,
+};
+
+use crate::model::{
+    Access, Adapter, Arg, Delegate, ErrMode, FwdDecl, FwdDerive, Method, RcvKind, RetMode,
+    TraitImpl,
+};
+
+mod access;
+
+impl FwdDerive {
+    pub fn implement(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (traits, decls): (Vec<&FwdDecl>, Vec<&FwdDecl>) = self
+            .decls
+            .iter()
+            .partition(|x| matches!(x.delegate, Delegate::Trait(_)));
+        let impls = decls.into_iter().map(FwdDecl::implement);
+        let trait_impls = traits.into_iter().map(FwdDecl::implement);
+
+        quote!(
+            impl #impl_generics #ident #ty_generics #where_clause { #(#impls) * }
+            #(#trait_impls) *
+        )
+    }
+}
+
+impl FwdDecl {
+    pub fn implement(&self) -> TokenStream {
+        match &self.delegate {
+            Delegate::MethodList(meths) => {
+                let impls = self.implement_methods(meths, quote!());
+
+                quote!(#(#impls) *)
+            }
+            Delegate::Trait(tr) => self.implement_trait(tr),
+        }
+    }
+
+    pub fn implement_pub(&self) -> TokenStream {
+        match &self.delegate {
+            Delegate::MethodList(meths) => {
+                let impls = self.implement_methods(meths, quote!(pub));
+
+                quote!(#(#impls) *)
+            }
+            Delegate::Trait(tr) => Error::new_spanned(
+                &tr.path,
+                "trait delegates cannot be made public, use 'fwd!' instead",
+            )
+            .to_compile_error(),
+        }
+    }
+
+    fn implement_methods(&self, meths: &[Method], default_vis: TokenStream) -> Vec<TokenStream> {
+        meths
+            .iter()
+            .map(|meth| {
+                self.implement_method(meth, &default_vis)
+                    .unwrap_or_else(|err| err.to_compile_error())
+            })
+            .collect()
+    }
+
+    fn implement_method(&self, meth: &Method, default_vis: &TokenStream) -> Result<TokenStream> {
+        let outer = self.access.outer_method(meth)?;
+        let attrs = &meth.attrs;
+        let vis = match &meth.vis {
+            Visibility::Inherited => default_vis.clone(),
+            vis => vis.to_token_stream(),
+        };
+        let sig = quote_signature(&bind_future_lifetime(&outer));
+        let place = self.access.quote_place(&self.target, meth)?;
+        let name = inner_ident(meth);
+        let turbofish = quote_turbofish(meth);
+        let arg_names = match &meth.template {
+            Some(template) => {
+                require_template_args(meth, template)?;
+                quote!(#template)
+            }
+            None => {
+                let values = self.quote_arg_values(&meth.args)?;
+                quote!(#(#values),*)
+            }
+        };
+        let await_fut = quote_await(meth);
+
+        let call = quote_unsafe(meth, quote!(#place.#name #turbofish(#arg_names)));
+        let inner = quote_inner_ret(meth, quote!(#call #await_fut))?;
+        let ret = quote_via(meth, quote_ret(meth, inner))?;
+        let converted = self.quote_err_conversion(meth, ret)?;
+
+        let body = self.access.quote_body(&self.target, meth, converted);
+
+        Ok(quote!(#(#attrs) * #vis #sig { #body }))
+    }
+
+    fn implement_trait(&self, tr: &TraitImpl) -> TokenStream {
+        let (impl_generics, _, where_clause) = tr.generics.split_for_impl();
+        let path = &tr.path;
+        let self_ty = &tr.self_ty;
+        let items = &tr.items;
+
+        let impls = tr.methods.iter().map(|meth| {
+            self.implement_trait_method(tr, meth)
+                .unwrap_or_else(|err| err.to_compile_error())
+        });
+
+        quote!(impl #impl_generics #path for #self_ty #where_clause { #(#items) * #(#impls) * })
+    }
+
+    fn implement_trait_method(&self, tr: &TraitImpl, meth: &Method) -> Result<TokenStream> {
+        if !self.access.is_signature_preserved(meth) {
+            return Err(Error::new_spanned(
+                &meth.ident,
+                format!(
+                    "target modifier changes the signature of trait method '{}'",
+                    meth.ident
+                ),
+            ));
+        }
+
+        if meth.rcv_kind == RcvKind::Shared {
+            return Err(Error::new_spanned(
+                &meth.rcv,
+                "trait methods taking 'Rc<Self>' or 'Arc<Self>' cannot be forwarded, the target is not reference counted",
+            ));
+        }
+
+        let path = &tr.path;
+        let attrs = &meth.attrs;
+        let name = &meth.ident;
+        let sig = quote_signature(meth);
+        let turbofish = quote_turbofish(meth);
+        let place = self.access.quote_place(&self.target, meth)?;
+        let target = quote_rcv_target(meth.rcv_kind, place);
+        let arg_names = self.quote_arg_values(&meth.args)?;
+        let await_fut = quote_await(meth);
+        let call = quote_unsafe(
+            meth,
+            quote!(<_ as #path>::#name #turbofish(#target #(, #arg_names) *)),
+        );
+
+        let inner = quote_inner_ret(meth, quote!(#call #await_fut))?;
+        let ret = quote_via(meth, quote_ret(meth, inner))?;
+        let converted = self.quote_err_conversion(meth, ret)?;
+
+        let body = self.access.quote_body(&self.target, meth, converted);
+
+        Ok(quote!(#(#attrs) * #sig { #body }))
+    }
+
+    fn quote_arg_values(&self, args: &[Arg]) -> Result<Vec<TokenStream>> {
+        args.iter()
+            .enumerate()
+            .map(|(idx, arg)| {
+                let binding = arg_binding((idx, arg));
+                match (&arg.adapter, SelfArg::of(&arg.ty)) {
+                    (Some(Adapter::Chain(chain)), _) => Ok(quote!(#binding #chain)),
+                    (Some(Adapter::Func(path)), _) => Ok(quote!(#path(#binding))),
+                    (None, Some(self_arg)) => self.quote_self_projection(arg, self_arg, &binding),
+                    (None, None) => Ok(quote!(#binding)),
+                }
+            })
+            .collect()
+    }
+
+    fn quote_self_projection(
+        &self,
+        arg: &Arg,
+        self_arg: SelfArg,
+        binding: &Ident,
+    ) -> Result<TokenStream> {
+        if self.access != Access::Direct {
+            return Err(Error::new_spanned(
+                &arg.ty,
+                "'Self' parameters can only be projected onto targets without modifiers",
+            ));
+        }
+
+        let projected = replace_self(
+            quote_target(&self.target),
+            &TokenTree::Ident(binding.clone()),
+        );
+        Ok(match self_arg {
+            SelfArg::Owned => projected,
+            SelfArg::Ref => quote!(&#projected),
+            SelfArg::RefMut => quote!(&mut #projected),
+            SelfArg::OptionRef => quote!(#binding.map(|#binding| &#projected)),
+            SelfArg::OptionRefMut => quote!(#binding.map(|#binding| &mut #projected)),
+        })
+    }
+
+    fn quote_err_conversion(&self, meth: &Method, call: TokenStream) -> Result<TokenStream> {
+        let Some(mode) = &meth.opts.err else {
+            return Ok(call);
+        };
+
+        if !is_ret_result(meth) || is_ret_future(meth) {
+            return Err(Error::new_spanned(
+                &meth.ret,
+                "error conversion requires the method to return a 'Result'",
+            ));
+        }
+        if let Some(inner_ret) = meth.inner_ret.as_ref().filter(|x| !is_result_type(x)) {
+            return Err(Error::new_spanned(
+                inner_ret,
+                "error conversion requires the inner type declared with 'from' to be a 'Result'",
+            ));
+        }
+
+        Ok(match mode {
+            ErrMode::From => quote!(#call.map_err(::core::convert::From::from)),
+            ErrMode::MapErr(path) => quote!(#call.map_err(#path)),
+            ErrMode::Context(path) => {
+                let context = format!(
+                    "{} forwarded to {}",
+                    meth.ident,
+                    quote_context(&self.target)
+                );
+
+                quote!(#call.map_err(|err| #path(#context, err)))
+            }
+        })
+    }
+}
+
+fn quote_signature(meth: &Method) -> TokenStream {
+    let constness = &meth.constness;
+    let unsafety = &meth.unsafety;
+    let abi = &meth.abi;
+    let name = &meth.ident;
+    let generics = &meth.generics;
+    let where_clause = &meth.generics.where_clause;
+    let args = quote_args(meth);
+
+    if is_ret_future(meth) {
+        let output = match &meth.ret {
+            ReturnType::Default => quote!(()),
+            ReturnType::Type(_, ty) => quote!(#ty),
+        };
+        let lifetime = future_lifetime();
+        let bound = if meth.generics.lifetimes().any(|x| x.lifetime == lifetime) {
+            quote!(+ #lifetime)
+        } else {
+            quote!()
+        };
+
+        quote!(#constness #unsafety #abi fn #name #generics(#args) -> impl ::core::future::Future<Output = #output> #bound #where_clause)
+    } else {
+        let asyncness = &meth.asyncness;
+        let ret = &meth.ret;
+
+        quote!(#constness #asyncness #unsafety #abi fn #name #generics(#args) #ret #where_clause)
+    }
+}
+
+fn bind_future_lifetime(meth: &Method) -> Method {
+    let mut meth = meth.clone();
+    if !is_ret_future(&meth) {
+        return meth;
+    }
+
+    let lifetime = future_lifetime();
+    let mut elided = bind_elided(&mut meth.rcv.ty, &lifetime);
+    if let Some((_, rcv_lifetime @ None)) = &mut meth.rcv.reference {
+        *rcv_lifetime = Some(lifetime.clone());
+    }
+    for arg in &mut meth.args {
+        elided |= bind_elided(&mut arg.ty, &lifetime);
+    }
+
+    if !elided && meth.generics.lifetimes().next().is_none() {
+        return meth;
+    }
+
+    let bounds: Vec<WherePredicate> = meth
+        .generics
+        .lifetimes()
+        .map(|x| {
+            let param = &x.lifetime;
+            parse_quote!(#param: #lifetime)
+        })
+        .chain(meth.generics.type_params().map(|x| {
+            let param = &x.ident;
+            parse_quote!(#param: #lifetime)
+        }))
+        .collect();
+
+    meth.generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+    );
+    meth.generics.make_where_clause().predicates.extend(bounds);
+    for arg in &mut meth.args {
+        if let Type::ImplTrait(x) = arg.ty.as_mut() {
+            x.bounds.push(TypeParamBound::Lifetime(lifetime.clone()));
+        }
+    }
+
+    meth
+}
+
+fn future_lifetime() -> Lifetime {
+    Lifetime::new("'__fwd", Span::mixed_site())
+}
+
+fn bind_elided(ty: &mut Type, lifetime: &Lifetime) -> bool {
+    match ty {
+        Type::Reference(x) => {
+            let elided = x.lifetime.is_none();
+            if elided {
+                x.lifetime = Some(lifetime.clone());
+            }
+
+            bind_elided(&mut x.elem, lifetime) || elided
+        }
+        Type::Path(x) => {
+            let qself = match &mut x.qself {
+                Some(qself) => bind_elided(&mut qself.ty, lifetime),
+                None => false,
+            };
+
+            bind_elided_path(&mut x.path, lifetime) || qself
+        }
+        Type::Tuple(x) => {
+            let mut elided = false;
+            for ty in &mut x.elems {
+                elided |= bind_elided(ty, lifetime);
+            }
+
+            elided
+        }
+        Type::Array(x) => bind_elided(&mut x.elem, lifetime),
+        Type::Slice(x) => bind_elided(&mut x.elem, lifetime),
+        Type::Ptr(x) => bind_elided(&mut x.elem, lifetime),
+        Type::Paren(x) => bind_elided(&mut x.elem, lifetime),
+        Type::Group(x) => bind_elided(&mut x.elem, lifetime),
+        Type::ImplTrait(x) => bind_elided_bounds(x.bounds.iter_mut(), lifetime),
+        Type::TraitObject(x) => bind_elided_bounds(x.bounds.iter_mut(), lifetime),
+        _ => false,
+    }
+}
+
+fn bind_elided_path(path: &mut Path, lifetime: &Lifetime) -> bool {
+    let mut elided = false;
+    for segment in &mut path.segments {
+        // Elided lifetimes in 'Fn(&T) -> &U' sugar are higher-ranked and must stay as they are
+        let PathArguments::AngleBracketed(generics) = &mut segment.arguments else {
+            continue;
+        };
+
+        for arg in &mut generics.args {
+            elided |= match arg {
+                GenericArgument::Lifetime(x) if x.ident == "_" => {
+                    *x = lifetime.clone();
+                    true
+                }
+                GenericArgument::Type(ty) => bind_elided(ty, lifetime),
+                GenericArgument::AssocType(x) => bind_elided(&mut x.ty, lifetime),
+                _ => false,
+            };
+        }
+    }
+
+    elided
+}
+
+fn bind_elided_bounds<'a>(
+    bounds: impl Iterator<Item = &'a mut TypeParamBound>,
+    lifetime: &Lifetime,
+) -> bool {
+    let mut elided = false;
+    for bound in bounds {
+        elided |= match bound {
+            TypeParamBound::Trait(x) => bind_elided_path(&mut x.path, lifetime),
+            TypeParamBound::Lifetime(x) if x.ident == "_" => {
+                *x = lifetime.clone();
+                true
+            }
+            _ => false,
+        };
+    }
+
+    elided
+}
+
+fn quote_inner_ret(meth: &Method, call: TokenStream) -> Result<TokenStream> {
+    let Some(inner_ret) = &meth.inner_ret else {
+        return Ok(call);
+    };
+
+    if is_ret_future(meth) {
+        return Err(Error::new_spanned(
+            inner_ret,
+            "the inner type of futures cannot be declared with 'from' without awaiting them",
+        ));
+    }
+
+    let ret = Ident::new("ret", Span::mixed_site());
+    Ok(quote!({
+        let #ret: #inner_ret = #call;
+        #ret
+    }))
+}
+
+fn quote_unsafe(meth: &Method, call: TokenStream) -> TokenStream {
+    if meth.unsafety.is_some() {
+        quote!(unsafe { #call })
+    } else {
+        call
+    }
+}
+
+fn quote_await(meth: &Method) -> TokenStream {
+    if meth.asyncness.is_some() && !is_ret_future(meth) {
+        quote!(.await)
+    } else {
+        quote!()
+    }
+}
+
+fn quote_ret(meth: &Method, call: TokenStream) -> TokenStream {
+    match meth.opts.ret.unwrap_or_default() {
+        RetMode::AsIs => call,
+        RetMode::Clone => quote!(#call.clone()),
+        RetMode::Copy => quote!(*#call),
+        RetMode::ToOwned => quote!(#call.to_owned()),
+        RetMode::Into => quote!(#call.into()),
+        RetMode::Auto if is_clone_implied(meth) => quote!(#call.clone()),
+        RetMode::Auto => call,
+    }
+}
+
+fn quote_via(meth: &Method, call: TokenStream) -> Result<TokenStream> {
+    let Some(via) = &meth.via else {
+        return Ok(call);
+    };
+
+    if is_ret_future(meth) {
+        return Err(Error::new_spanned(
+            via,
+            "mapping with 'via' cannot be applied to futures without awaiting them",
+        ));
+    }
+
+    Ok(quote!({
+        fn __fwd_via<T, U>(ret: T, map: impl ::core::ops::FnOnce(T) -> U) -> U {
+            map(ret)
+        }
+        __fwd_via(#call, #via)
+    }))
+}
+
+fn is_clone_implied(meth: &Method) -> bool {
+    is_rcv_ref(meth)
+        && is_ret_val_not_option(meth)
+        && !is_ret_future(meth)
+        && meth.constness.is_none()
+}
+
+fn quote_context(target: &Expr) -> String {
+    let mut context = target.to_token_stream().to_string();
+    for (spaced, tight) in [
+        (" . ", "."),
+        (" (", "("),
+        ("( ", "("),
+        (" )", ")"),
+        (" [", "["),
+    ] {
+        context = context.replace(spaced, tight);
+    }
+
+    context
+}
+
+fn inner_ident(meth: &Method) -> &Ident {
+    meth.inner_ident.as_ref().unwrap_or(&meth.ident)
+}
+
+fn quote_args(meth: &Method) -> TokenStream {
+    let rcv = &meth.rcv;
+    let args = meth.args.iter().enumerate().map(|(idx, arg)| {
+        let attrs = &arg.attrs;
+        let ty = &arg.ty;
+
+        if meth.template.is_some() {
+            let pat = &arg.pat;
+            quote!(#(#attrs) * #pat: #ty)
+        } else {
+            let binding = arg_binding((idx, arg));
+            quote!(#(#attrs) * #binding: #ty)
+        }
+    });
+
+    quote!(#rcv #(, #args) *)
+}
+
+fn quote_target(target: &Expr) -> TokenStream {
+    match target {
+        Expr::Unary(_) => quote!((#target)),
+        _ => quote!(#target),
+    }
+}
+
+fn quote_rcv_target(rcv_kind: RcvKind, target: TokenStream) -> TokenStream {
+    match rcv_kind {
+        RcvKind::RefMut => quote!(&mut #target),
+        RcvKind::Ref | RcvKind::Shared => quote!(&#target),
+        RcvKind::Boxed => quote!(::std::boxed::Box::new(#target)),
+        RcvKind::Value | RcvKind::PinRef | RcvKind::PinMut => target,
+    }
+}
+
+fn require_template_args(meth: &Method, template: &Punctuated<Expr, Token![,]>) -> Result<()> {
+    let mut bound = Vec::new();
+    for arg in &meth.args {
+        collect_bindings(&arg.pat, &mut bound);
+    }
+
+    match bound.into_iter().find(|x| {
+        !x.to_string().starts_with('_') && !template.iter().any(|expr| uses_ident(expr, x))
+    }) {
+        Some(unused) => Err(Error::new_spanned(
+            &unused,
+            format!("parameter '{}' is not used in the call template", unused),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn uses_ident(expr: &Expr, ident: &Ident) -> bool {
+    let uses = |x: &Expr| uses_ident(x, ident);
+
+    match expr {
+        Expr::Path(x) => x.qself.is_none() && x.path.is_ident(ident),
+        Expr::Field(x) => uses(&x.base),
+        Expr::MethodCall(x) => uses(&x.receiver) || x.args.iter().any(uses),
+        Expr::Call(x) => uses(&x.func) || x.args.iter().any(uses),
+        Expr::Index(x) => uses(&x.expr) || uses(&x.index),
+        Expr::Binary(x) => uses(&x.left) || uses(&x.right),
+        Expr::Reference(x) => uses(&x.expr),
+        Expr::Unary(x) => uses(&x.expr),
+        Expr::Paren(x) => uses(&x.expr),
+        Expr::Group(x) => uses(&x.expr),
+        Expr::Cast(x) => uses(&x.expr),
+        Expr::Try(x) => uses(&x.expr),
+        Expr::Await(x) => uses(&x.base),
+        Expr::Tuple(x) => x.elems.iter().any(uses),
+        Expr::Array(x) => x.elems.iter().any(uses),
+        Expr::Struct(x) => {
+            x.fields.iter().any(|x| uses(&x.expr)) || x.rest.as_deref().is_some_and(uses)
+        }
+        Expr::Range(x) => {
+            x.start.as_deref().is_some_and(uses) || x.end.as_deref().is_some_and(uses)
+        }
+        Expr::Lit(_) => false,
+        // Macro arguments and blocks are only checked for the identifier appearing at all
+        _ => has_ident(expr.to_token_stream(), ident),
+    }
+}
+
+fn collect_bindings(pat: &Pat, bound: &mut Vec<Ident>) {
+    match pat {
+        Pat::Ident(x) => {
+            bound.push(x.ident.clone());
+            if let Some((_, subpat)) = &x.subpat {
+                collect_bindings(subpat, bound)
+            }
+        }
+        Pat::Tuple(x) => x.elems.iter().for_each(|x| collect_bindings(x, bound)),
+        Pat::TupleStruct(x) => x.elems.iter().for_each(|x| collect_bindings(x, bound)),
+        Pat::Slice(x) => x.elems.iter().for_each(|x| collect_bindings(x, bound)),
+        Pat::Struct(x) => x
+            .fields
+            .iter()
+            .for_each(|x| collect_bindings(&x.pat, bound)),
+        Pat::Reference(x) => collect_bindings(&x.pat, bound),
+        Pat::Paren(x) => collect_bindings(&x.pat, bound),
+        Pat::Type(x) => collect_bindings(&x.pat, bound),
+        _ => {}
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SelfArg {
+    Owned,
+    Ref,
+    RefMut,
+    OptionRef,
+    OptionRefMut,
+}
+
+impl SelfArg {
+    fn of(ty: &Type) -> Option<SelfArg> {
+        match ty {
+            Type::Path(x) if x.qself.is_none() && x.path.is_ident("Self") => Some(SelfArg::Owned),
+            Type::Reference(x) if is_self_type(&x.elem) => match x.mutability {
+                Some(_) => Some(SelfArg::RefMut),
+                None => Some(SelfArg::Ref),
+            },
+            Type::Path(x) if x.qself.is_none() => {
+                let seg = x.path.segments.last()?;
+                let PathArguments::AngleBracketed(args) = &seg.arguments else {
+                    return None;
+                };
+                match (seg.ident == "Option", args.args.first(), args.args.len()) {
+                    (true, Some(GenericArgument::Type(ty)), 1) => match SelfArg::of(ty)? {
+                        SelfArg::Ref => Some(SelfArg::OptionRef),
+                        SelfArg::RefMut => Some(SelfArg::OptionRefMut),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            Type::Paren(x) => SelfArg::of(&x.elem),
+            _ => None,
+        }
+    }
+}
+
+fn is_self_type(ty: &Type) -> bool {
+    matches!(SelfArg::of(ty), Some(SelfArg::Owned))
+}
+
+fn replace_self(tokens: TokenStream, replacement: &TokenTree) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(x) if x == "self" => replacement.clone(),
+            TokenTree::Group(x) => {
+                let mut group = Group::new(x.delimiter(), replace_self(x.stream(), replacement));
+                group.set_span(x.span());
+                TokenTree::Group(group)
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+fn arg_binding((idx, arg): (usize, &Arg)) -> Ident {
+    match arg.pat.as_ref() {
+        Pat::Ident(x) if x.by_ref.is_none() && x.subpat.is_none() => x.ident.clone(),
+        _ => Ident::new(&format!("arg{}", idx), Span::mixed_site()),
+    }
+}
+
+fn quote_turbofish(meth: &Method) -> TokenStream {
+    let inferred = meth.generics.type_params().all(|param| {
+        meth.args
+            .iter()
+            .any(|arg| has_ident(arg.ty.to_token_stream(), &param.ident))
+    });
+
+    if inferred {
+        return quote!();
+    }
+
+    let params = meth.generics.params.iter().filter_map(|param| match param {
+        GenericParam::Type(x) => Some(&x.ident),
+        GenericParam::Const(x) => Some(&x.ident),
+        GenericParam::Lifetime(_) => None,
+    });
+
+    quote!(::<#(#params),*>)
+}
+
+fn has_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(x) => x == *ident,
+        TokenTree::Group(x) => has_ident(x.stream(), ident),
+        _ => false,
+    })
+}
+
+fn is_ret_future(meth: &Method) -> bool {
+    meth.asyncness.is_some() && meth.opts.no_await
+}
+
+fn is_rcv_ref(meth: &Method) -> bool {
+    !matches!(meth.rcv_kind, RcvKind::Value | RcvKind::Boxed)
+}
+
+fn is_rcv_mut(meth: &Method) -> bool {
+    matches!(meth.rcv_kind, RcvKind::RefMut | RcvKind::PinMut)
+}
+
+fn is_ret_result(meth: &Method) -> bool {
+    match &meth.ret {
+        ReturnType::Type(_, ty) => is_result_type(ty),
+        ReturnType::Default => false,
+    }
+}
+
+fn is_result_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(x) => x.path.segments.last().is_some_and(|x| x.ident == "Result"),
+        _ => false,
+    }
+}
+
+fn is_ret_val_not_option(meth: &Method) -> bool {
+    if let ReturnType::Type(_, typ) = meth.ret.clone() {
+        !is_option_type(typ.as_ref())
+    } else {
+        true
+    }
+}
+
+fn is_option_type(typ: &Type) -> bool {
+    match typ {
+        Type::Path(x) => has_option_token(&x.path),
+        Type::Reference(x) => is_option_type(x.elem.as_ref()),
+        _ => false,
+    }
+}
+
+fn has_option_token(path: &Path) -> bool {
+    path.segments.last().is_some_and(|x| x.ident == "Option")
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use test_case::test_case;
+
+    use crate::model::{
+        FwdDeclBuilder, FwdDeriveBuilder, MethodBuilder, RcvKind, RetMode, TraitImplBuilder,
+    };
+
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(MethodBuilder::default().ident("test").rcv()),
+        quote!(fn test(self) { self.tester.test() });
+        "should implement method forwarding to named member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(MethodBuilder::default().ident("test").rcv()),
+        quote!(fn test(self) { self.42.test() });
+        "should implement method forwarding to unnamed member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(
+            MethodBuilder::default().ident("test").with_arg("arg1: u8").with_arg("arg2: &str").rcv()
+        ),
+        quote!(fn test(self, arg1: u8, arg2: &str) { self.42.test(arg1, arg2) });
+        "should implement method with arguments forwarding to unnamed member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ref_mut_rcv().with_arg("mut val: Vec<u8>")
+        ),
+        quote!(fn test(&mut self, val: Vec<u8>) { self.tester.test(val) });
+        "should implement method dropping mutability from argument binding"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().with_arg("_: Token").with_arg("(a, b): (u8, u8)")
+                .with_arg("Point { x, .. }: Point").with_arg("ref name: String")
+        ),
+        quote!(fn test(&self, arg0: Token, arg1: (u8, u8), arg2: Point, arg3: String) {
+            self.tester.test(arg0, arg1, arg2, arg3)
+        });
+        "should implement method with fresh bindings for argument patterns"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().with_arg("name: impl Into<String> => .into()")
+                .with_arg("(a, b): (u8, u8) => Pair::from").with_arg("path: impl AsRef<Path> => .as_ref()")
+        ),
+        quote!(fn test(&self, name: impl Into<String>, arg1: (u8, u8), path: impl AsRef<Path>) {
+            self.tester.test(name.into(), Pair::from(arg1), path.as_ref())
+        });
+        "should implement method adapting arguments"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").with_method(
+            MethodBuilder::default().ident("merge").ref_mut_rcv().with_arg("other: &Self").with_arg("mut spare: Self")
+                .with_arg("scratch: &mut Self")
+        ),
+        quote!(fn merge(&mut self, other: &Self, spare: Self, scratch: &mut Self) {
+            self.inner.merge(&other.inner, spare.inner, &mut scratch.inner)
+        });
+        "should implement method projecting self parameters onto target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self.items[self.idx]").with_method(
+            MethodBuilder::default().ident("merge").ref_mut_rcv().with_arg("other: Option<&'a Self>")
+                .with_arg("last: Option<&mut Self>")
+        ),
+        quote!(fn merge(&mut self, other: Option<&'a Self>, last: Option<&mut Self>) {
+            self.items[self.idx].merge(
+                other.map(|other| &other.items[other.idx]),
+                last.map(|last| &mut last.items[last.idx])
+            )
+        });
+        "should implement method projecting optional self parameters onto target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").with_trait(
+            TraitImplBuilder::default().path("PartialEq").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("eq").ref_rcv().with_arg("other: &Self").ret("-> bool"))
+        ),
+        quote!(impl PartialEq for Outer {
+            fn eq(&self, other: &Self) -> bool { <_ as PartialEq>::eq(&self.inner, &other.inner) }
+        });
+        "should implement trait method projecting self parameters onto target"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("db").with_method(
+            MethodBuilder::default().ident("find").ref_rcv().with_arg("id: Id").with_arg("(a, _): (u8, u8)")
+                .with_arg("_unused: bool").ret("-> Option<Row>").template("a, id, &self.tenant, Mode::Strict")
+        ),
+        quote!(fn find(&self, id: Id, (a, _): (u8, u8), _unused: bool) -> Option<Row> {
+            self.db.find(a, id, &self.tenant, Mode::Strict)
+        });
+        "should implement method with call template"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("db").with_method(
+            MethodBuilder::default().ident("find").ref_rcv().with_arg("id: Id").with_arg("Point { x, y }: Point")
+                .template("id, x")
+        ),
+        quote!(::core::compile_error! { "parameter 'y' is not used in the call template" });
+        "should require every parameter to be used in call template"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("db").with_method(
+            MethodBuilder::default().ident("find").ref_rcv().with_arg("id: u64").with_arg("tenant: &str")
+                .template("&self.tenant, id")
+        ),
+        quote!(::core::compile_error! { "parameter 'tenant' is not used in the call template" });
+        "should not count field members as parameter uses in call template"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("db").with_method(
+            MethodBuilder::default().ident("find").ref_rcv().with_arg("id: u64").with_arg("tenant: &str")
+                .template("format!(\"{}/{}\", tenant, id)")
+        ),
+        quote!(fn find(&self, id: u64, tenant: &str) {
+            self.db.find(format!("{}/{}", tenant, id))
+        });
+        "should count macro arguments as parameter uses in call template"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(
+            MethodBuilder::default().ident("test").rcv().ret("-> String")
+        ),
+        quote!(fn test(self) -> String { self.42.test() });
+        "should implement method with return value forwarding to unnamed member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> String")
+        ),
+        quote!(fn test(&self) -> String { self.42.test() });
+        "should implement method with return value forwarding to unnamed member reference"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ret_mode(RetMode::Clone).ref_rcv().ret("-> String")
+        ),
+        quote!(fn test(&self) -> String { self.tester.test().clone() });
+        "should implement method cloning return value"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ret_mode(RetMode::Copy).ref_rcv().ret("-> u8")
+        ),
+        quote!(fn test(&self) -> u8 { *self.tester.test() });
+        "should implement method copying return value"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ret_mode(RetMode::ToOwned).ref_rcv().ret("-> String")
+        ),
+        quote!(fn test(&self) -> String { self.tester.test().to_owned() });
+        "should implement method converting return value to owned"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().ident("test").ret_mode(RetMode::Into).rcv().ret("-> u64")
+        ),
+        quote!(async fn test(self) -> u64 { self.tester.test().await.into() });
+        "should implement method converting awaited return value into declared type"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ret_mode(RetMode::Auto).ref_rcv().ret("-> String")
+        ),
+        quote!(fn test(&self) -> String { self.tester.test().clone() });
+        "should implement method cloning return value with auto mode"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ret_mode(RetMode::Auto).ref_rcv().ret("-> std::option::Option<u8>")
+        ),
+        quote!(fn test(&self) -> std::option::Option<u8> { self.tester.test() });
+        "should implement method keeping option return value with auto mode"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ret_mode(RetMode::Auto).ref_rcv().ret("-> Option::Iter")
+        ),
+        quote!(fn test(&self) -> Option::Iter { self.tester.test().clone() });
+        "should implement method cloning non option return value with auto mode"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").err_mode("from").ref_rcv().ret("-> Result<u8, Error>")
+        ),
+        quote!(fn test(&self) -> Result<u8, Error> { self.tester.test().map_err(::core::convert::From::from) });
+        "should implement method converting error with from"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").err_mode("map_err(Error::Store)").ref_rcv().ret("-> io::Result<u8>")
+        ),
+        quote!(fn test(&self) -> io::Result<u8> { self.tester.test().map_err(Error::Store) });
+        "should implement method mapping error with path"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self.inner().store").with_method(
+            MethodBuilder::default().asyncness().ident("test").err_mode("context(Error::forwarded)").rcv().ret("-> Result<u8, Error>")
+        ),
+        quote!(async fn test(self) -> Result<u8, Error> {
+            self.inner().store.test().await.map_err(|err| Error::forwarded("test forwarded to self.inner().store", err))
+        });
+        "should implement method wrapping error with context"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").err_mode("from").ref_rcv().ret("-> Option<u8>")
+        ),
+        quote!(::core::compile_error! { "error conversion requires the method to return a 'Result'" });
+        "should require result return value for error conversion"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").err_mode("from").ref_rcv().ret("-> Result<u8, Error>")
+                .inner_ret("io::Result<u8>")
+        ),
+        quote!(fn test(&self) -> Result<u8, Error> {
+            {
+                let ret: io::Result<u8> = self.tester.test();
+                ret
+            }.map_err(::core::convert::From::from)
+        });
+        "should implement method converting error from declared inner type"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").err_mode("from").ref_rcv().ret("-> Result<u8, Error>")
+                .inner_ret("Option<u8>")
+        ),
+        quote!(::core::compile_error! {
+            "error conversion requires the inner type declared with 'from' to be a 'Result'"
+        });
+        "should require result inner type for error conversion"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().no_await().ident("test").rcv().ret("-> u8").inner_ret("u16")
+        ),
+        quote!(::core::compile_error! {
+            "the inner type of futures cannot be declared with 'from' without awaiting them"
+        });
+        "should reject declared inner type for returned future"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> OuterId").via("OuterId::from")
+        ),
+        quote!(fn test(&self) -> OuterId {
+            {
+                fn __fwd_via<T, U>(ret: T, map: impl ::core::ops::FnOnce(T) -> U) -> U { map(ret) }
+                __fwd_via(self.tester.test(), OuterId::from)
+            }
+        });
+        "should implement method mapping return value with path"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").err_mode("from").ref_rcv().ret("-> Result<Vec<Node>, Error>")
+                .via("|x| x.map(|x| x.map(Node::from).collect())")
+        ),
+        quote!(fn test(&self) -> Result<Vec<Node>, Error> {
+            {
+                fn __fwd_via<T, U>(ret: T, map: impl ::core::ops::FnOnce(T) -> U) -> U { map(ret) }
+                __fwd_via(self.tester.test(), |x| x.map(|x| x.map(Node::from).collect()))
+            }.map_err(::core::convert::From::from)
+        });
+        "should implement method mapping return value with closure before error conversion"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().no_await().ident("test").ref_rcv().ret("-> OuterId").via("OuterId::from")
+        ),
+        quote!(::core::compile_error! { "mapping with 'via' cannot be applied to futures without awaiting them" });
+        "should reject return value mapping for returned futures"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> Option<String>")
+        ),
+        quote!(fn test(&self) -> Option<String> { self.42.test() });
+        "should implement method with option return value forwarding to unnamed member reference"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> &Option<String>")
+        ),
+        quote!(fn test(&self) -> &Option<String> { self.42.test() });
+        "should implement method with option reference return value forwarding to unnamed member reference"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(42).with_method(
+            MethodBuilder::default().ident("test").ref_rcv().ret("-> &mut Option<String>")
+        ),
+        quote!(fn test(&self) -> &mut Option<String> { self.42.test() });
+        "should implement method with mutable option reference return value forwarding to unnamed member reference"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").generics("<T: Into<String> >").ref_rcv().with_arg("val: T")
+        ),
+        quote!(fn test<T: Into<String> >(&self, val: T) { self.tester.test(val) });
+        "should implement generic method with inferred parameters"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").generics("<'a, T: FromStr, const N: usize>").ref_rcv()
+                .with_arg("val: [u8; N]").ret("-> Option<T>")
+        ),
+        quote!(fn test<'a, T: FromStr, const N: usize>(&self, val: [u8; N]) -> Option<T> { self.tester.test::<T, N>(val) });
+        "should implement generic method with turbofish for parameters only used in return value"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").generics("<F>").ref_mut_rcv().with_arg("f: F").ret("-> Option<usize>")
+                .where_clause("where F: FnMut(&u8) -> bool, Self: Sized")
+        ),
+        quote!(fn test<F>(&mut self, f: F) -> Option<usize> where F: FnMut(&u8) -> bool, Self: Sized { self.tester.test(f) });
+        "should implement method with where clause"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("test").generics("<'a, 'b: 'a>").lifetime_ref_mut_rcv("'a")
+                .with_arg("key: &'b str").ret("-> Option<&'a String>")
+        ),
+        quote!(fn test<'a, 'b: 'a>(&'a mut self, key: &'b str) -> Option<&'a String> { self.tester.test(key) });
+        "should implement method with lifetime parameters"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().ident("test").rcv().with_arg("id: u64").ret("-> String")
+        ),
+        quote!(async fn test(self, id: u64) -> String { self.tester.test(id).await });
+        "should implement async method awaiting forwarded future"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().ident("test").ref_rcv().ret("-> Option<String>")
+        ),
+        quote!(async fn test(&self) -> Option<String> { self.tester.test().await });
+        "should implement async method with reference receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().no_await().ident("test").ref_rcv().with_arg("id: u64").ret("-> String")
+        ),
+        quote!(fn test<'__fwd>(&'__fwd self, id: u64) -> impl ::core::future::Future<Output = String> + '__fwd {
+            self.tester.test(id)
+        });
+        "should implement async method returning forwarded future"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().no_await().ident("fetch").ref_rcv().with_arg("key: &str")
+                .with_arg("keys: Vec<Cow<'_, str>>").ret("-> usize")
+        ),
+        quote!(fn fetch<'__fwd>(&'__fwd self, key: &'__fwd str, keys: Vec<Cow<'__fwd, str> >)
+            -> impl ::core::future::Future<Output = usize> + '__fwd {
+            self.tester.fetch(key, keys)
+        });
+        "should bind borrowed arguments of forwarded future to a single lifetime"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().no_await().ident("fetch").generics("<'a, T>").lifetime_ref_rcv("'a")
+                .with_arg("key: T").with_arg("check: impl Fn(&str) -> bool").ret("-> usize")
+        ),
+        quote!(fn fetch<'__fwd, 'a, T>(&'a self, key: T, check: impl Fn(&str) -> bool + '__fwd)
+            -> impl ::core::future::Future<Output = usize> + '__fwd where 'a: '__fwd, T: '__fwd {
+            self.tester.fetch(key, check)
+        });
+        "should bound lifetimes, type parameters and impl trait arguments of forwarded future"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().no_await().ident("test").rcv()
+        ),
+        quote!(fn test(self) -> impl ::core::future::Future<Output = ()> { self.tester.test() });
+        "should implement async method returning forwarded future from moved receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_rcv().with_arg("#[allow(unused)] (a, _): (u8, u8)"))
+        ),
+        quote!(impl Tester for Outer {
+            fn test(&self, #[allow(unused)] arg0: (u8, u8)) { <_ as Tester>::test(&self.tester, arg0) }
+        });
+        "should implement trait method with fresh bindings for argument patterns"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("iter").with_trait(
+            TraitImplBuilder::default().path("Iterator").self_ty("Outer").with_item("type Item = u8;")
+                .with_method(MethodBuilder::default().ident("next").ref_mut_rcv().ret("-> Option<u8>"))
+        ),
+        quote!(impl Iterator for Outer {
+            type Item = u8;
+            fn next(&mut self) -> Option<u8> { <_ as Iterator>::next(&mut self.iter) }
+        });
+        "should implement trait with associated items"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").typed_rcv("self: Box<Self>", RcvKind::Boxed).ret("-> u8"))
+        ),
+        quote!(impl Tester for Outer {
+            fn test(self: Box<Self>) -> u8 { <_ as Tester>::test(::std::boxed::Box::new((*self).tester)) }
+        });
+        "should rebox target of boxed trait method receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").typed_rcv("self: Rc<Self>", RcvKind::Shared))
+        ),
+        quote!(impl Tester for Outer {
+            ::core::compile_error! {
+                "trait methods taking 'Rc<Self>' or 'Arc<Self>' cannot be forwarded, the target is not reference counted"
+            }
+        });
+        "should reject reference counted trait method receiver"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().asyncness().ident("test").ref_rcv().ret("-> String"))
+        ),
+        quote!(impl Tester for Outer {
+            async fn test(&self) -> String { <_ as Tester>::test(&self.tester).await }
+        });
+        "should implement async trait method forwarding"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().constness().ident("test").ref_rcv().ret("-> usize")
+        ),
+        quote!(const fn test(&self) -> usize { self.tester.test() });
+        "should implement const method without cloning"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().unsafety().ident("test").rcv().with_arg("idx: usize").ret("-> u8")
+        ),
+        quote!(unsafe fn test(self, idx: usize) -> u8 { unsafe { self.tester.test(idx) } });
+        "should implement unsafe method wrapping forwarded call"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().abi("extern \"C\"").ident("test").rcv()
+        ),
+        quote!(extern "C" fn test(self) { self.tester.test() });
+        "should implement method with abi"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().asyncness().unsafety().ident("test").rcv()
+        ),
+        quote!(async unsafe fn test(self) { unsafe { self.tester.test() } .await });
+        "should implement async unsafe method awaiting unsafe call"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().unsafety().ident("test").ref_rcv())
+        ),
+        quote!(impl Tester for Outer {
+            unsafe fn test(&self) { unsafe { <_ as Tester>::test(&self.tester) } }
+        });
+        "should implement unsafe trait method forwarding"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().with_attr("/// Tests things").with_attr("#[inline]").ident("test").rcv()
+        ),
+        quote!(#[doc = " Tests things"] #[inline] fn test(self) { self.tester.test() });
+        "should implement method with attributes"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester")
+            .with_method(MethodBuilder::default().with_attr("#[inline]").vis("pub").ident("test_a").rcv())
+            .with_method(MethodBuilder::default().vis("pub(crate)").ident("test_b").rcv())
+            .with_method(MethodBuilder::default().ident("test_c").rcv()),
+        quote!(
+            #[inline] pub fn test_a(self) { self.tester.test_a() }
+            pub(crate) fn test_b(self) { self.tester.test_b() }
+            fn test_c(self) { self.tester.test_c() }
+        );
+        "should implement methods with mixed visibility"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_method(
+            MethodBuilder::default().ident("outer").inner_ident("inner").generics("<T>").ref_rcv().ret("-> Option<T>")
+        ),
+        quote!(fn outer<T>(&self) -> Option<T> { self.tester.inner::<T>() });
+        "should implement renamed method"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("inner").named_target("config").unnamed_target(0).with_method(
+            MethodBuilder::default().ident("test").rcv()
+        ),
+        quote!(fn test(self) { self.inner.config.0.test() });
+        "should implement method forwarding to nested member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self.inner().get_backend(42)").with_method(
+            MethodBuilder::default().ident("test").rcv().with_arg("val: u8")
+        ),
+        quote!(fn test(self, val: u8) { self.inner().get_backend(42).test(val) });
+        "should implement method forwarding to method call result"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("*self.inner").with_method(
+            MethodBuilder::default().ident("test").rcv()
+        ),
+        quote!(fn test(self) { (*self.inner).test() });
+        "should implement method forwarding to dereferenced member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().with_attr("#[inline]").ident("test").rcv())
+        ),
+        quote!(impl Tester for Outer {
+            #[inline] fn test(self) { <_ as Tester>::test(self.tester) }
+        });
+        "should implement trait method with attributes"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test_a").ref_rcv().ret("-> String"))
+                .with_method(MethodBuilder::default().ident("test_b").ref_mut_rcv().with_arg("val: u8"))
+                .with_method(MethodBuilder::default().ident("test_c").rcv())
+        ),
+        quote!(impl Tester for Outer {
+            fn test_a(&self) -> String { <_ as Tester>::test_a(&self.tester) }
+            fn test_b(&mut self, val: u8) { <_ as Tester>::test_b(&mut self.tester, val) }
+            fn test_c(self) { <_ as Tester>::test_c(self.tester) }
+        });
+        "should implement trait forwarding to named member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(0).with_trait(
+            TraitImplBuilder::default().generics("<T>").where_clause("where T: Tester<T>").path("Tester<T>").self_ty("Outer<T>")
+                .with_method(MethodBuilder::default().ident("test").ref_rcv())
+        ),
+        quote!(impl<T> Tester<T> for Outer<T> where T: Tester<T> {
+            fn test(&self) { <_ as Tester<T> >::test(&self.0) }
+        });
+        "should implement generic trait forwarding to unnamed member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().unnamed_target(0).named_target("tester").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_mut_rcv())
+        ),
+        quote!(impl Tester for Outer {
+            fn test(&mut self) { <_ as Tester>::test(&mut self.0.tester) }
+        });
+        "should implement trait forwarding to nested member"
+    )]
+    #[test_case(
+        FwdDeclBuilder::default().target("self.items[0]").with_trait(
+            TraitImplBuilder::default().path("Tester").self_ty("Outer")
+                .with_method(MethodBuilder::default().ident("test").ref_rcv())
+        ),
+        quote!(impl Tester for Outer {
+            fn test(&self) { <_ as Tester>::test(&self.items[0]) }
+        });
+        "should implement trait forwarding to indexed member"
+    )]
+    fn should_write_forwarding_impl(input: &FwdDeclBuilder, want: TokenStream) {
+        let decl = input.build().unwrap();
+
+        assert_eq!(decl.implement().to_string(), want.to_string())
+    }
+
+    #[test]
+    fn should_write_public_forwarding_impl() {
+        let decls = FwdDeclBuilder::default()
+            .named_target("tester")
+            .with_method(
+                MethodBuilder::default()
+                    .with_attr("#[inline]")
+                    .ident("test1")
+                    .rcv(),
+            )
+            .with_method(MethodBuilder::default().ident("test2").rcv())
+            .build()
+            .unwrap();
+
+        let want = quote!(
+            #[inline]
+            pub fn test1(self) {
+                self.tester.test1()
+            }
+            pub fn test2(self) {
+                self.tester.test2()
+            }
+        );
+
+        assert_eq!(decls.implement_pub().to_string(), want.to_string())
+    }
+
+    #[test]
+    fn should_write_public_forwarding_impl_with_explicit_visibility() {
+        let decls = FwdDeclBuilder::default()
+            .named_target("tester")
+            .with_method(MethodBuilder::default().ident("test1").rcv())
+            .with_method(
+                MethodBuilder::default()
+                    .vis("pub(crate)")
+                    .ident("test2")
+                    .rcv(),
+            )
+            .build()
+            .unwrap();
+
+        let want = quote!(
+            pub fn test1(self) {
+                self.tester.test1()
+            }
+            pub(crate) fn test2(self) {
+                self.tester.test2()
+            }
+        );
+
+        assert_eq!(decls.implement_pub().to_string(), want.to_string())
+    }
+
+    #[test]
+    fn should_not_write_public_trait_forwarding_impl() {
+        let decl = FwdDeclBuilder::default()
+            .named_target("tester")
+            .with_trait(
+                TraitImplBuilder::default()
+                    .path("Tester")
+                    .self_ty("Outer")
+                    .with_method(MethodBuilder::default().ident("test").rcv()),
+            )
+            .build()
+            .unwrap();
+
+        let want = quote!(::core::compile_error! {
+            "trait delegates cannot be made public, use 'fwd!' instead"
+        });
+
+        assert_eq!(decl.implement_pub().to_string(), want.to_string())
+    }
+
+    #[test_case(
+        FwdDeriveBuilder::default().ident("Tester").with_decl(
+            FwdDeclBuilder::default().named_target("inner").with_method(MethodBuilder::default().ident("test").rcv())
+        ),
+        quote!(impl Tester { fn test(self) { self.inner.test() } });
+        "should implement derived forwarding in inherent impl"
+    )]
+    #[test_case(
+        FwdDeriveBuilder::default().ident("Tester").generics("<T: Clone>")
+            .with_decl(FwdDeclBuilder::default().named_target("inner").with_method(MethodBuilder::default().ident("test").rcv()))
+            .with_decl(FwdDeclBuilder::default().unnamed_target(1).with_method(MethodBuilder::default().ident("other").rcv())),
+        quote!(impl<T: Clone> Tester<T> { fn test(self) { self.inner.test() } fn other(self) { self.1.other() } });
+        "should implement derived forwarding for generic struct"
+    )]
+    #[test_case(
+        FwdDeriveBuilder::default().ident("Tester")
+            .with_decl(FwdDeclBuilder::default().named_target("inner").with_trait(
+                TraitImplBuilder::default().path("Tester").self_ty("Tester").with_method(MethodBuilder::default().ident("test").rcv())
+            ))
+            .with_decl(FwdDeclBuilder::default().named_target("inner").with_method(MethodBuilder::default().ident("other").rcv())),
+        quote!(
+            impl Tester { fn other(self) { self.inner.other() } }
+            impl Tester for Tester { fn test(self) { <_ as Tester>::test(self.inner) } }
+        );
+        "should implement derived trait forwarding outside inherent impl"
+    )]
+    fn should_write_derived_forwarding_impl(input: &FwdDeriveBuilder, want: TokenStream) {
+        let derive = input.build().unwrap();
+
+        assert_eq!(derive.implement().to_string(), want.to_string())
+    }
+}
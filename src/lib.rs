@@ -6,6 +6,56 @@ mod implement;
 mod model;
 mod parse;
 
+/// Forwards the declared methods to a target rooted at `self`.
+///
+/// Trait impls declare their methods in braces, along with any associated `type` and `const`
+/// items, which are emitted as written. The braces may be omitted for `Display`, `Debug` and
+/// the other formatting traits when named bare, as `fmt::Trait` or as `core::fmt::Trait`
+/// and `std::fmt::Trait`.
+///
+/// When a single method is forwarded to a target ending in a call to the same inner
+/// method, such as `to self.db.find(id, &self.tenant)`, that call is used as a template
+/// for the arguments, and every declared parameter must appear in it. Targets of several
+/// methods or of trait impls cannot end in a call to one of the forwarded methods.
+///
+/// A modifier before the target changes how it is reached. `borrow` and `try_borrow` go
+/// through a `RefCell`, and `lock` and `rwlock` through a `Mutex` or `RwLock`, taking the
+/// write lock for methods declared with `&mut self`. The generated methods take `&self` in
+/// both cases. `try_borrow` returns `Result<T, BorrowError>`, or `BorrowMutError` for
+/// `&mut self` methods. Poisoned locks panic, unless declared as `lock(recover)`, which uses
+/// the poisoned guard, or `lock(result)`, which returns `Result<T, PoisonError<()>>`. Lock
+/// targets reject return types borrowing from the guard, such as `&T` or `Iter<'_, T>`.
+/// Lifetimes elided in paths, as in `Iter<T>`, cannot be detected and are reported by the
+/// borrow checker at the declared return type.
+///
+/// An optional target `to self.field?` forwards to the value of an `Option`, and methods
+/// returning `T` return `Option<T>` instead. With a fallback, the declared type is kept:
+/// `self.field? else default` returns `T::default()` and `else panic("message")` panics when
+/// the option is `None`.
+///
+/// `each` forwards to every element of a collection, which requires an aggregator for
+/// methods returning values. `each(vec)` returns `Vec<T>`, `each(all)` and `each(any)`
+/// combine booleans, `each(first)` returns the first `Some` and `each(sum)` sums the results.
+/// `each(result)` stops at the first `Err`, and methods returning `Result<T, E>` with a `T`
+/// other than `()` return `Result<Vec<T>, E>`.
+///
+/// Forwarded calls return the target's value as is. A `#[fwd(ret = ...)]` option on a
+/// method converts it instead: `clone`, `copy`, `to_owned` or `into`. `ret = auto` clones
+/// values returned through a reference receiver unless they are options or futures.
+/// Declaring `-> Out via map` passes the forwarded value to `map`, a path or closure,
+/// which must produce `Out`.
+///
+/// Errors of returned results are converted with `#[fwd(err = from)]`, `err = map_err(path)`
+/// or `err = context(path)`, which calls `path("method forwarded to target", err)`. The type
+/// returned by the target can be declared as `-> Outer from Inner`. Options in a leading
+/// `#![fwd(...)]` apply to every forwarded method that does not set them.
+///
+/// Besides `self`, `&self` and `&mut self`, methods may take `self: Box<Self>`, which moves
+/// the target out of the box, and `self: Rc<Self>` or `self: Arc<Self>`, which borrow it.
+/// `self: Pin<&mut Self>` and `self: Pin<&Self>` are forwarded with `to unsafe pin self.field`,
+/// which projects the pin onto the field. The caller guarantees that the field is structurally
+/// pinned: the type never moves it out of a pinned reference, does not implement `Unpin`
+/// unless the field does, and does not move it in `Drop`.
 #[proc_macro]
 pub fn fwd(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as model::FwdDecl)
@@ -13,9 +63,32 @@ pub fn fwd(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Forwards the declared methods like [`fwd!`], making them `pub` by default.
+///
+/// Methods declared with a visibility of their own, such as `pub(crate) fn len(&self)`, keep
+/// it. Trait impls cannot be forwarded with `fwd_pub!`, as their visibility is the trait's.
 #[proc_macro]
 pub fn fwd_pub(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as model::FwdDecl)
         .implement_pub()
         .into()
 }
+
+/// Derives inherent methods and trait impls forwarding to the fields of a struct or to the
+/// payloads of an enum.
+///
+/// On a struct, each `#[forward(...)]` attribute goes on the field to forward to, named or
+/// unnamed, and declares methods or a trait impl as in [`fwd!`] without the `to` target:
+/// `#[forward(fn len(&self) -> usize, impl fmt::Display for Outer)]`. A field may carry
+/// several attributes. Struct-level attributes are rejected.
+///
+/// On an enum, the attributes go on the enum itself, and the generated methods match on
+/// `self` to forward to the payload of whichever variant it holds. Every variant must have
+/// exactly one field, in tuple or struct form, and the payloads of all variants must provide
+/// the forwarded methods. Attributes on variants or their fields are rejected.
+#[proc_macro_derive(Forward, attributes(forward))]
+pub fn derive_forward(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as model::FwdDerive)
+        .implement()
+        .into()
+}
@@ -1,104 +1,489 @@
 use std::fmt::{Debug, Formatter};
 
 use derive_builder::Builder;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
-use syn::{Member, PatType, Receiver, ReturnType};
+use syn::punctuated::Punctuated;
+use syn::{
+    Abi, Attribute, Expr, Generics, ImplItem, Pat, Path, Receiver, ReturnType, Token, Type,
+    Visibility,
+};
 
-#[derive(Builder, Clone, PartialEq)]
+#[derive(Builder, Clone)]
+pub struct FwdDerive {
+    #[builder(setter(custom))]
+    pub ident: Ident,
+    #[builder(setter(custom), default = "Generics::default()")]
+    pub generics: Generics,
+    #[builder(setter(custom), default = "Vec::new()")]
+    pub decls: Vec<FwdDecl>,
+}
+
+#[derive(Builder, Clone)]
 pub struct FwdDecl {
     #[builder(setter(custom))]
     pub delegate: Delegate,
     #[builder(setter(custom))]
-    pub target: Member,
+    pub target: Expr,
+    #[builder(setter(custom), default)]
+    pub access: Access,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Access {
+    #[default]
+    Direct,
+    Borrow,
+    TryBorrow,
+    Lock(Poison),
+    RwLock(Poison),
+    Optional(Option<Fallback>),
+    Variants(Vec<Payload>),
+    Each(Option<Aggregate>),
+    Pin,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Poison {
+    #[default]
+    Panic,
+    Recover,
+    Result,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fallback {
+    Default,
+    Panic(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Aggregate {
+    Vec,
+    All,
+    Any,
+    First,
+    Sum,
+    Result,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payload {
+    pub variant: Ident,
+    pub field: Option<Ident>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Delegate {
     MethodList(Vec<Method>),
+    Trait(Box<TraitImpl>),
+}
+
+#[derive(Builder, Clone)]
+pub struct TraitImpl {
+    #[builder(setter(custom), default = "Generics::default()")]
+    pub generics: Generics,
+    #[builder(setter(custom))]
+    pub path: Path,
+    #[builder(setter(custom))]
+    pub self_ty: Type,
+    #[builder(setter(custom), default = "Vec::new()")]
+    pub items: Vec<ImplItem>,
+    #[builder(setter(custom), default = "Vec::new()")]
+    pub methods: Vec<Method>,
 }
 
 #[derive(Builder, Clone)]
 pub struct Method {
+    #[builder(setter(custom), default = "Vec::new()")]
+    pub attrs: Vec<Attribute>,
+    #[builder(setter(custom), default)]
+    pub opts: Options,
+    #[builder(setter(custom), default = "Visibility::Inherited")]
+    pub vis: Visibility,
+    #[builder(setter(custom), default)]
+    pub constness: Option<Token![const]>,
+    #[builder(setter(custom), default)]
+    pub asyncness: Option<Token![async]>,
+    #[builder(setter(custom), default)]
+    pub unsafety: Option<Token![unsafe]>,
+    #[builder(setter(custom), default)]
+    pub abi: Option<Abi>,
     #[builder(setter(custom))]
     pub ident: Ident,
+    #[builder(setter(custom), default)]
+    pub inner_ident: Option<Ident>,
+    #[builder(setter(custom), default = "Generics::default()")]
+    pub generics: Generics,
     #[builder(setter(custom))]
     pub rcv: Receiver,
+    #[builder(setter(custom))]
+    pub rcv_kind: RcvKind,
     #[builder(setter(custom), default = "Vec::new()")]
-    pub args: Vec<PatType>,
+    pub args: Vec<Arg>,
     #[builder(setter(custom), default = "ReturnType::Default")]
     pub ret: ReturnType,
+    #[builder(setter(custom), default)]
+    pub inner_ret: Option<Box<Type>>,
+    #[builder(setter(custom), default)]
+    pub via: Option<Expr>,
+    #[builder(setter(custom), default)]
+    pub template: Option<Punctuated<Expr, Token![,]>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RcvKind {
+    Value,
+    Ref,
+    RefMut,
+    Boxed,
+    Shared,
+    PinRef,
+    PinMut,
+}
+
+#[derive(Clone)]
+pub struct Arg {
+    pub attrs: Vec<Attribute>,
+    pub pat: Box<Pat>,
+    pub ty: Box<Type>,
+    pub adapter: Option<Adapter>,
+}
+
+#[derive(Clone)]
+pub enum Adapter {
+    Chain(TokenStream),
+    Func(Path),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Options {
+    pub no_await: bool,
+    pub ret: Option<RetMode>,
+    pub err: Option<ErrMode>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RetMode {
+    #[default]
+    AsIs,
+    Clone,
+    Copy,
+    ToOwned,
+    Into,
+    Auto,
+}
+
+#[derive(Clone)]
+pub enum ErrMode {
+    From,
+    MapErr(Path),
+    Context(Path),
+}
+
+impl PartialEq for FwdDerive {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident
+            && eq_tokens(&self.generics, &other.generics)
+            && self.decls == other.decls
+    }
+}
+
+impl Debug for FwdDerive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let generics = self.generics.to_token_stream();
+
+        write!(f, "{}{} {:?}", self.ident, generics, self.decls)
+    }
 }
 
 impl Debug for FwdDecl {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let tokens = self.target.to_token_stream();
 
-        write!(f, "{:?} to {}", self.delegate, tokens)
+        write!(f, "{:?} to {:?} {}", self.delegate, self.access, tokens)
+    }
+}
+
+impl PartialEq for FwdDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.delegate == other.delegate
+            && eq_tokens(&self.target, &other.target)
+            && self.access == other.access
+    }
+}
+
+impl PartialEq for TraitImpl {
+    fn eq(&self, other: &Self) -> bool {
+        eq_tokens(&self.generics, &other.generics)
+            && eq_tokens(&self.generics.where_clause, &other.generics.where_clause)
+            && eq_tokens(&self.path, &other.path)
+            && eq_tokens(&self.self_ty, &other.self_ty)
+            && self.items.len() == other.items.len()
+            && self
+                .items
+                .iter()
+                .zip(&other.items)
+                .all(|(a, b)| eq_tokens(a, b))
+            && self.methods == other.methods
+    }
+}
+
+impl Debug for TraitImpl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let generics = self.generics.to_token_stream();
+        let path = self.path.to_token_stream();
+        let self_ty = self.self_ty.to_token_stream();
+        let items: Vec<_> = self
+            .items
+            .iter()
+            .map(|x| x.to_token_stream().to_string())
+            .collect();
+
+        write!(
+            f,
+            "impl{} {} for {} {:?} {:?}",
+            generics, path, self_ty, items, self.methods
+        )
+    }
+}
+
+impl PartialEq for ErrMode {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ErrMode::From, ErrMode::From) => true,
+            (ErrMode::MapErr(a), ErrMode::MapErr(b)) => eq_tokens(a, b),
+            (ErrMode::Context(a), ErrMode::Context(b)) => eq_tokens(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Debug for ErrMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrMode::From => write!(f, "from"),
+            ErrMode::MapErr(path) => write!(f, "map_err({})", path.to_token_stream()),
+            ErrMode::Context(path) => write!(f, "context({})", path.to_token_stream()),
+        }
+    }
+}
+
+impl PartialEq for Adapter {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Adapter::Chain(a), Adapter::Chain(b)) => eq_tokens(a, b),
+            (Adapter::Func(a), Adapter::Func(b)) => eq_tokens(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Adapter::Chain(chain) => write!(f, "{}", chain),
+            Adapter::Func(path) => write!(f, "{}", path.to_token_stream()),
+        }
     }
 }
 
 impl PartialEq for Method {
     fn eq(&self, other: &Self) -> bool {
-        self.ident == other.ident
+        eq_attrs(&self.attrs, &other.attrs)
+            && self.opts == other.opts
+            && eq_tokens(&self.vis, &other.vis)
+            && self.constness.is_some() == other.constness.is_some()
+            && self.asyncness.is_some() == other.asyncness.is_some()
+            && self.unsafety.is_some() == other.unsafety.is_some()
+            && eq_tokens(&self.abi, &other.abi)
+            && self.ident == other.ident
+            && self.inner_ident == other.inner_ident
+            && eq_tokens(&self.generics, &other.generics)
+            && eq_tokens(&self.generics.where_clause, &other.generics.where_clause)
             && eq_rcv(&self.rcv, &other.rcv)
-            && eq_args(self.args.clone(), other.args.clone())
+            && self.rcv_kind == other.rcv_kind
+            && eq_args(&self.args, &other.args)
             && eq_ret(&self.ret, &other.ret)
+            && eq_tokens(&self.inner_ret, &other.inner_ret)
+            && eq_tokens(&self.via, &other.via)
+            && eq_tokens(&self.template, &other.template)
     }
 }
 
 impl Debug for Method {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut args = Vec::<String>::new();
+        let mut args = vec![self.rcv.to_token_stream().to_string()];
         for arg in &self.args {
-            args.push(arg.to_token_stream().to_string())
+            let pat = arg.pat.to_token_stream();
+            let ty = arg.ty.to_token_stream();
+            match &arg.adapter {
+                Some(adapter) => args.push(format!("{}: {} => {:?}", pat, ty, adapter)),
+                None => args.push(format!("{}: {}", pat, ty)),
+            }
         }
 
-        write!(f, "fn {}({})", self.ident, args.join(", "))?;
+        let generics = self.generics.to_token_stream();
+
+        for attr in &self.attrs {
+            write!(f, "{} ", attr.to_token_stream())?
+        }
+        if !matches!(self.vis, Visibility::Inherited) {
+            write!(f, "{} ", self.vis.to_token_stream())?
+        }
+        if self.constness.is_some() {
+            write!(f, "const ")?
+        }
+        if self.asyncness.is_some() {
+            write!(f, "async ")?
+        }
+        if self.unsafety.is_some() {
+            write!(f, "unsafe ")?
+        }
+        if let Some(abi) = &self.abi {
+            write!(f, "{} ", abi.to_token_stream())?
+        }
+        write!(f, "fn {}", self.ident)?;
+        if let Some(inner_ident) = &self.inner_ident {
+            write!(f, " as {}", inner_ident)?
+        }
+        write!(f, "{}({})", generics, args.join(", "))?;
         if let ReturnType::Type(_, ty) = &self.ret {
             write!(f, " -> {}", ty.to_token_stream())?
         }
+        if let Some(inner_ret) = &self.inner_ret {
+            write!(f, " from {}", inner_ret.to_token_stream())?
+        }
+        if let Some(via) = &self.via {
+            write!(f, " via {}", via.to_token_stream())?
+        }
+        if let Some(template) = &self.template {
+            write!(f, " calling ({})", template.to_token_stream())?
+        }
+        if let Some(where_clause) = &self.generics.where_clause {
+            write!(f, " {}", where_clause.to_token_stream())?
+        }
 
         Ok(())
     }
 }
 
-fn eq_args(a: Vec<PatType>, b: Vec<PatType>) -> bool {
-    a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| eq_pat(a, b))
+fn eq_attrs(a: &[Attribute], b: &[Attribute]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| eq_tokens(a, b))
+}
+
+fn eq_args(a: &[Arg], b: &[Arg]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| eq_pat(a, b) && a.adapter == b.adapter)
 }
 
 fn eq_ret(a: &ReturnType, b: &ReturnType) -> bool {
-    a.to_token_stream().to_string() == b.to_token_stream().to_string()
+    eq_tokens(a, b)
 }
 
 fn eq_rcv(a: &Receiver, b: &Receiver) -> bool {
     a.reference.is_some() == b.reference.is_some()
         && a.mutability.is_some() == b.mutability.is_some()
+        && eq_rcv_lifetime(a, b)
+        && eq_rcv_ty(a, b)
 }
 
-fn eq_pat(a: &PatType, b: &PatType) -> bool {
-    a.pat.to_token_stream().to_string() == b.pat.to_token_stream().to_string()
+fn eq_rcv_ty(a: &Receiver, b: &Receiver) -> bool {
+    match (a.colon_token, b.colon_token) {
+        (Some(_), Some(_)) => eq_tokens(&a.ty, &b.ty),
+        (a, b) => a.is_some() == b.is_some(),
+    }
+}
+
+fn eq_rcv_lifetime(a: &Receiver, b: &Receiver) -> bool {
+    let lifetime = |x: &Receiver| x.reference.as_ref().and_then(|(_, lt)| lt.clone());
+
+    lifetime(a) == lifetime(b)
+}
+
+fn eq_pat(a: &Arg, b: &Arg) -> bool {
+    eq_tokens(&a.pat, &b.pat)
+}
+
+fn eq_tokens(a: &impl ToTokens, b: &impl ToTokens) -> bool {
+    a.to_token_stream().to_string() == b.to_token_stream().to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use proc_macro2::Span;
     use quote::{format_ident, quote, IdentFragment};
-    use syn::{FnArg, Index, Member};
+    use syn::parse::Parser;
+    use syn::punctuated::Punctuated;
+    use syn::{parse_quote, Attribute, Expr, Index, Member, Token};
+    use test_case::test_case;
+
+    use crate::model::{
+        Access, Arg, Delegate, FwdDeclBuilder, FwdDeriveBuilder, MethodBuilder, Options, Payload,
+        RcvKind, RetMode, TraitImplBuilder,
+    };
+
+    impl FwdDeriveBuilder {
+        pub fn ident(&mut self, name: impl IdentFragment) -> &mut Self {
+            self.ident = Some(format_ident!("{}", name));
+            self
+        }
+
+        pub fn generics(&mut self, generics: &str) -> &mut Self {
+            self.generics = Some(syn::parse_str(generics).unwrap());
+            self
+        }
 
-    use crate::model::{Delegate, FwdDeclBuilder, MethodBuilder};
+        pub fn with_decl(&mut self, decl: &FwdDeclBuilder) -> &mut Self {
+            match &mut self.decls {
+                None => self.decls = Some(vec![decl.build().unwrap()]),
+                Some(decls) => decls.push(decl.build().unwrap()),
+            };
+            self
+        }
+    }
 
     impl FwdDeclBuilder {
         pub fn named_target(&mut self, ident: &str) -> &mut Self {
-            self.target = Some(Member::Named(format_ident!("{}", ident)));
-            self
+            self.with_member(Member::Named(format_ident!("{}", ident)))
         }
 
         pub fn unnamed_target(&mut self, idx: u32) -> &mut Self {
-            self.target = Some(Member::Unnamed(Index {
+            self.with_member(Member::Unnamed(Index {
                 index: idx,
                 span: Span::call_site(),
-            }));
+            }))
+        }
+
+        pub fn access(&mut self, access: Access) -> &mut Self {
+            self.access = Some(access);
+            self
+        }
+
+        pub fn target(&mut self, target: &str) -> &mut Self {
+            self.target = Some(syn::parse_str(target).unwrap());
+            self
+        }
+
+        pub fn with_variant(&mut self, variant: &str, field: Option<&str>) -> &mut Self {
+            let payload = Payload {
+                variant: format_ident!("{}", variant),
+                field: field.map(|x| format_ident!("{}", x)),
+            };
+            match &mut self.access {
+                Some(Access::Variants(payloads)) => payloads.push(payload),
+                _ => self.access = Some(Access::Variants(vec![payload])),
+            };
+            self
+        }
+
+        fn with_member(&mut self, member: Member) -> &mut Self {
+            self.target = Some(match &self.target {
+                None => parse_quote!(self.#member),
+                Some(target) => parse_quote!(#target.#member),
+            });
             self
         }
 
@@ -110,42 +495,175 @@ mod tests {
             }
             self
         }
+
+        pub fn with_trait(&mut self, tr: &TraitImplBuilder) -> &mut Self {
+            self.delegate = Some(Delegate::Trait(Box::new(tr.build().unwrap())));
+            self
+        }
+    }
+
+    impl TraitImplBuilder {
+        pub fn generics(&mut self, generics: &str) -> &mut Self {
+            self.generics = Some(syn::parse_str(generics).unwrap());
+            self
+        }
+
+        pub fn where_clause(&mut self, where_clause: &str) -> &mut Self {
+            let generics = self.generics.get_or_insert_with(Default::default);
+            generics.where_clause = Some(syn::parse_str(where_clause).unwrap());
+            self
+        }
+
+        pub fn path(&mut self, path: &str) -> &mut Self {
+            self.path = Some(syn::parse_str(path).unwrap());
+            self
+        }
+
+        pub fn self_ty(&mut self, self_ty: &str) -> &mut Self {
+            self.self_ty = Some(syn::parse_str(self_ty).unwrap());
+            self
+        }
+
+        pub fn with_item(&mut self, item: &str) -> &mut Self {
+            let item = syn::parse_str(item).unwrap();
+            match &mut self.items {
+                None => self.items = Some(vec![item]),
+                Some(items) => items.push(item),
+            };
+            self
+        }
+
+        pub fn with_method(&mut self, meth: &MethodBuilder) -> &mut Self {
+            match &mut self.methods {
+                None => self.methods = Some(vec![meth.build().unwrap()]),
+                Some(methods) => methods.push(meth.build().unwrap()),
+            };
+            self
+        }
     }
 
     #[cfg(test)]
     impl MethodBuilder {
+        pub fn with_attr(&mut self, attr: &str) -> &mut Self {
+            let parsed = Attribute::parse_outer.parse_str(attr).unwrap();
+            self.attrs.get_or_insert_with(Vec::new).extend(parsed);
+            self
+        }
+
+        pub fn no_await(&mut self) -> &mut Self {
+            self.opts.get_or_insert_with(Default::default).no_await = true;
+            self
+        }
+
+        pub fn ret_mode(&mut self, mode: RetMode) -> &mut Self {
+            self.opts.get_or_insert_with(Default::default).ret = Some(mode);
+            self
+        }
+
+        pub fn err_mode(&mut self, mode: &str) -> &mut Self {
+            let attrs = Attribute::parse_outer
+                .parse_str(&format!("#[fwd(err = {})]", mode))
+                .unwrap();
+            let mut opts = Options::default();
+            opts.parse_attr(&attrs[0]).unwrap();
+            self.opts.get_or_insert_with(Default::default).err = opts.err;
+            self
+        }
+
+        pub fn vis(&mut self, vis: &str) -> &mut Self {
+            self.vis = Some(syn::parse_str(vis).unwrap());
+            self
+        }
+
+        pub fn constness(&mut self) -> &mut Self {
+            self.constness = Some(Some(Default::default()));
+            self
+        }
+
+        pub fn asyncness(&mut self) -> &mut Self {
+            self.asyncness = Some(Some(Default::default()));
+            self
+        }
+
+        pub fn unsafety(&mut self) -> &mut Self {
+            self.unsafety = Some(Some(Default::default()));
+            self
+        }
+
+        pub fn abi(&mut self, abi: &str) -> &mut Self {
+            self.abi = Some(Some(syn::parse_str(abi).unwrap()));
+            self
+        }
+
         pub fn ident(&mut self, name: impl IdentFragment) -> &mut Self {
             self.ident = Some(format_ident!("{}", name));
             self
         }
 
+        pub fn inner_ident(&mut self, name: impl IdentFragment) -> &mut Self {
+            self.inner_ident = Some(Some(format_ident!("{}", name)));
+            self
+        }
+
+        pub fn generics(&mut self, generics: &str) -> &mut Self {
+            self.generics = Some(syn::parse_str(generics).unwrap());
+            self
+        }
+
+        pub fn where_clause(&mut self, where_clause: &str) -> &mut Self {
+            let generics = self.generics.get_or_insert_with(Default::default);
+            generics.where_clause = Some(syn::parse_str(where_clause).unwrap());
+            self
+        }
+
         pub fn rcv(&mut self) -> &mut Self {
             self.rcv = Some(syn::parse2(quote!(self)).unwrap());
+            self.rcv_kind = Some(RcvKind::Value);
             self
         }
 
         pub fn mut_rcv(&mut self) -> &mut Self {
             self.rcv = Some(syn::parse2(quote!(mut self)).unwrap());
+            self.rcv_kind = Some(RcvKind::Value);
             self
         }
 
         pub fn ref_rcv(&mut self) -> &mut Self {
             self.rcv = Some(syn::parse2(quote!(&self)).unwrap());
+            self.rcv_kind = Some(RcvKind::Ref);
             self
         }
 
         pub fn ref_mut_rcv(&mut self) -> &mut Self {
             self.rcv = Some(syn::parse2(quote!(&mut self)).unwrap());
+            self.rcv_kind = Some(RcvKind::RefMut);
+            self
+        }
+
+        pub fn lifetime_ref_rcv(&mut self, lifetime: &str) -> &mut Self {
+            self.rcv = Some(syn::parse_str(&format!("&{} self", lifetime)).unwrap());
+            self.rcv_kind = Some(RcvKind::Ref);
+            self
+        }
+
+        pub fn lifetime_ref_mut_rcv(&mut self, lifetime: &str) -> &mut Self {
+            self.rcv = Some(syn::parse_str(&format!("&{} mut self", lifetime)).unwrap());
+            self.rcv_kind = Some(RcvKind::RefMut);
+            self
+        }
+
+        pub fn typed_rcv(&mut self, rcv: &str, kind: RcvKind) -> &mut Self {
+            self.rcv = Some(syn::parse_str(rcv).unwrap());
+            self.rcv_kind = Some(kind);
             self
         }
 
         pub fn with_arg(&mut self, arg: &str) -> &mut Self {
-            if let FnArg::Typed(pt) = syn::parse_str::<FnArg>(arg).unwrap() {
-                match &mut self.args {
-                    None => self.args = Some(vec![pt]),
-                    Some(args) => args.push(pt),
-                };
-            }
+            let arg = syn::parse_str::<Arg>(arg).unwrap();
+            match &mut self.args {
+                None => self.args = Some(vec![arg]),
+                Some(args) => args.push(arg),
+            };
             self
         }
 
@@ -153,5 +671,40 @@ mod tests {
             self.ret = Some(syn::parse_str(ret).unwrap());
             self
         }
+
+        pub fn inner_ret(&mut self, inner_ret: &str) -> &mut Self {
+            self.inner_ret = Some(Some(syn::parse_str(inner_ret).unwrap()));
+            self
+        }
+
+        pub fn template(&mut self, template: &str) -> &mut Self {
+            let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+            self.template = Some(Some(parser.parse_str(template).unwrap()));
+            self
+        }
+
+        pub fn via(&mut self, via: &str) -> &mut Self {
+            self.via = Some(Some(syn::parse_str(via).unwrap()));
+            self
+        }
+    }
+
+    #[test_case(
+        MethodBuilder::default().ident("test").lifetime_ref_rcv("'a"),
+        MethodBuilder::default().ident("test").ref_rcv();
+        "should distinguish named and elided receiver lifetimes"
+    )]
+    #[test_case(
+        MethodBuilder::default().ident("test").lifetime_ref_mut_rcv("'a"),
+        MethodBuilder::default().ident("test").lifetime_ref_mut_rcv("'b");
+        "should distinguish different receiver lifetimes"
+    )]
+    #[test_case(
+        MethodBuilder::default().ident("test").lifetime_ref_rcv("'a"),
+        MethodBuilder::default().ident("test").lifetime_ref_mut_rcv("'a");
+        "should distinguish receiver mutability with same lifetime"
+    )]
+    fn should_not_equal_method(a: &MethodBuilder, b: &MethodBuilder) {
+        assert_ne!(a.build().unwrap(), b.build().unwrap())
     }
 }
@@ -0,0 +1,175 @@
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, token, Error, Ident, LitStr, Result, Token};
+
+use crate::model::{Access, Aggregate, Fallback, Poison};
+
+impl Parse for Access {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![unsafe]) {
+            let unsafety: Token![unsafe] = input.parse()?;
+            return match input.parse::<Ident>() {
+                Ok(ident) if ident == "pin" => Ok(Access::Pin),
+                _ => Err(Error::new_spanned(
+                    unsafety,
+                    "unsupported unsafe target modifier, expected 'unsafe pin'",
+                )),
+            };
+        }
+        if !input.peek(Ident) || input.peek2(Token![.]) {
+            return Ok(Access::Direct);
+        }
+
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "borrow" => Ok(Access::Borrow),
+            "try_borrow" => Ok(Access::TryBorrow),
+            "lock" => Ok(Access::Lock(input.parse()?)),
+            "rwlock" => Ok(Access::RwLock(input.parse()?)),
+            "each" if input.peek(token::Paren) => Ok(Access::Each(Some(input.parse()?))),
+            "each" => Ok(Access::Each(None)),
+            _ => Err(Error::new(
+                ident.span(),
+                "unsupported target modifier, expected one of 'borrow', 'try_borrow', 'lock', 'rwlock' or 'each'",
+            )),
+        }
+    }
+}
+
+impl Parse for Poison {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if !input.peek(token::Paren) {
+            return Ok(Poison::Panic);
+        }
+
+        let policy_buf;
+        _ = parenthesized!(policy_buf in input);
+
+        let policy: Ident = policy_buf.parse()?;
+        match policy.to_string().as_str() {
+            "panic" => Ok(Poison::Panic),
+            "recover" => Ok(Poison::Recover),
+            "result" => Ok(Poison::Result),
+            _ => Err(Error::new(
+                policy.span(),
+                "unsupported poisoning policy, expected one of 'panic', 'recover' or 'result'",
+            )),
+        }
+    }
+}
+
+impl Parse for Aggregate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let aggregate_buf;
+        _ = parenthesized!(aggregate_buf in input);
+
+        let aggregate: Ident = aggregate_buf.parse()?;
+        match aggregate.to_string().as_str() {
+            "vec" => Ok(Aggregate::Vec),
+            "all" => Ok(Aggregate::All),
+            "any" => Ok(Aggregate::Any),
+            "first" => Ok(Aggregate::First),
+            "sum" => Ok(Aggregate::Sum),
+            "result" => Ok(Aggregate::Result),
+            _ => Err(Error::new(
+                aggregate.span(),
+                "unsupported aggregator, expected one of 'vec', 'all', 'any', 'first', 'sum' or 'result'",
+            )),
+        }
+    }
+}
+
+impl Parse for Fallback {
+    fn parse(input: ParseStream) -> Result<Self> {
+        _ = input.parse::<Token![else]>()?;
+
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "default" => Ok(Fallback::Default),
+            "panic" => {
+                let msg_buf;
+                _ = parenthesized!(msg_buf in input);
+
+                let msg: LitStr = msg_buf.parse()?;
+                Ok(Fallback::Panic(msg.value()))
+            }
+            _ => Err(Error::new(
+                ident.span(),
+                "unsupported fallback, expected one of 'default' or 'panic(\"message\")'",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use test_case::test_case;
+
+    use crate::model::{Access, Aggregate, Fallback, Poison};
+
+    #[test_case(quote!(), Access::Direct; "should parse direct access")]
+    #[test_case(quote!(borrow), Access::Borrow; "should parse borrow access")]
+    #[test_case(quote!(try_borrow), Access::TryBorrow; "should parse try borrow access")]
+    #[test_case(quote!(lock), Access::Lock(Poison::Panic); "should parse lock access")]
+    #[test_case(quote!(lock(recover)), Access::Lock(Poison::Recover); "should parse lock access with recovery")]
+    #[test_case(quote!(rwlock(panic)), Access::RwLock(Poison::Panic); "should parse rwlock access with panic")]
+    #[test_case(quote!(rwlock(result)), Access::RwLock(Poison::Result); "should parse rwlock access with result")]
+    #[test_case(quote!(each), Access::Each(None); "should parse fan-out access")]
+    #[test_case(quote!(each(first)), Access::Each(Some(Aggregate::First)); "should parse fan-out access with aggregator")]
+    #[test_case(quote!(unsafe pin), Access::Pin; "should parse pin projection access")]
+    fn should_parse_access(input: TokenStream, want: Access) {
+        let access = syn::parse2::<Access>(input).unwrap();
+
+        assert_eq!(access, want)
+    }
+
+    #[test_case(
+        quote!(lend),
+        "unsupported target modifier, expected one of 'borrow', 'try_borrow', 'lock', 'rwlock' or 'each'";
+        "should require known target modifier"
+    )]
+    #[test_case(
+        quote!(unsafe borrow),
+        "unsupported unsafe target modifier, expected 'unsafe pin'";
+        "should require pin after unsafe"
+    )]
+    #[test_case(
+        quote!(lock(ignore)),
+        "unsupported poisoning policy, expected one of 'panic', 'recover' or 'result'";
+        "should require known poisoning policy"
+    )]
+    #[test_case(
+        quote!(each(max)),
+        "unsupported aggregator, expected one of 'vec', 'all', 'any', 'first', 'sum' or 'result'";
+        "should require known aggregator"
+    )]
+    fn should_fail_to_parse_access(input: TokenStream, want: &str) {
+        let err = syn::parse2::<Access>(input).unwrap_err();
+
+        assert_eq!(err.to_string(), want)
+    }
+
+    #[test_case(quote!(else default), Fallback::Default; "should parse default fallback")]
+    #[test_case(
+        quote!(else panic("missing inner")), Fallback::Panic("missing inner".to_string());
+        "should parse panic fallback"
+    )]
+    fn should_parse_fallback(input: TokenStream, want: Fallback) {
+        let fallback = syn::parse2::<Fallback>(input).unwrap();
+
+        assert_eq!(fallback, want)
+    }
+
+    #[test_case(
+        quote!(else zero),
+        "unsupported fallback, expected one of 'default' or 'panic(\"message\")'";
+        "should require known fallback"
+    )]
+    #[test_case(quote!(else panic(42)), "expected string literal"; "should require panic message literal")]
+    fn should_fail_to_parse_fallback(input: TokenStream, want: &str) {
+        let err = syn::parse2::<Fallback>(input).unwrap_err();
+
+        assert_eq!(err.to_string(), want)
+    }
+}
@@ -1,12 +1,12 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Error, Member, Result, Token};
+use syn::{Error, Expr, Result, Token, UnOp};
 
-use crate::model::{Delegate, FwdDecl};
+use crate::model::{Access, Delegate, FwdDecl, Method};
 
 impl Parse for FwdDecl {
     fn parse(input: ParseStream) -> Result<Self> {
-        let delegate: Delegate = input.parse()?;
+        let mut delegate: Delegate = input.parse()?;
 
         let ident: Ident = input.parse()?;
         if ident != Ident::new("to", input.cursor().span()) {
@@ -16,12 +16,111 @@ impl Parse for FwdDecl {
             ));
         }
 
-        _ = input.parse::<Token![self]>()?;
-        _ = input.parse::<Token![.]>()?;
+        if input.peek(Token![match]) {
+            return Err(Error::new(
+                input.span(),
+                "'match self' targets need the enum variants, derive 'Forward' on the enum instead",
+            ));
+        }
+
+        let mut access: Access = input.parse()?;
+        let mut target: Expr = input.parse()?;
+        if let Expr::Try(x) = target {
+            if access != Access::Direct {
+                return Err(Error::new_spanned(
+                    x,
+                    "optional targets cannot be combined with target modifiers",
+                ));
+            }
 
-        let target: Member = input.parse()?;
+            let fallback = if input.peek(Token![else]) {
+                Some(input.parse()?)
+            } else {
+                None
+            };
+
+            access = Access::Optional(fallback);
+            target = *x.expr;
+        }
+        if !is_rooted_at_self(&target) {
+            return Err(Error::new_spanned(
+                target,
+                "target must be an expression rooted at self, such as 'self.field' or 'self.method()'",
+            ));
+        }
 
-        Ok(FwdDecl { delegate, target })
+        let target = split_template(&mut delegate, target)?;
+
+        Ok(FwdDecl {
+            delegate,
+            target,
+            access,
+        })
+    }
+}
+
+fn split_template(delegate: &mut Delegate, target: Expr) -> Result<Expr> {
+    let (meths, is_trait) = match delegate {
+        Delegate::MethodList(meths) => (meths, false),
+        Delegate::Trait(tr) => (&mut tr.methods, true),
+    };
+    let call = match target {
+        Expr::MethodCall(call) if meths.iter().any(|x| is_template_of(x, &call.method)) => call,
+        _ => return Ok(target),
+    };
+
+    if is_trait {
+        return Err(Error::new_spanned(
+            &call.method,
+            "call templates cannot be used to forward trait methods",
+        ));
+    }
+    let [meth] = &mut meths[..] else {
+        return Err(Error::new_spanned(
+            &call.method,
+            format!(
+                "call templates can only forward a single method, declare '{}' in its own 'fwd!'",
+                call.method
+            ),
+        ));
+    };
+
+    if let Some(turbofish) = &call.turbofish {
+        return Err(Error::new_spanned(
+            turbofish,
+            "call templates cannot specify generic arguments, declare them on the forwarded method instead",
+        ));
+    }
+    if let Some(arg) = meth.args.iter().find(|x| x.adapter.is_some()) {
+        return Err(Error::new_spanned(
+            &arg.pat,
+            "call templates cannot be combined with argument adapters",
+        ));
+    }
+
+    meth.template = Some(call.args);
+    Ok(*call.receiver)
+}
+
+fn is_template_of(meth: &Method, name: &Ident) -> bool {
+    meth.inner_ident.as_ref().unwrap_or(&meth.ident) == name
+}
+
+fn is_rooted_at_self(target: &Expr) -> bool {
+    match target {
+        Expr::Field(x) => is_self_or_rooted(&x.base),
+        Expr::MethodCall(x) => is_self_or_rooted(&x.receiver),
+        Expr::Index(x) => is_self_or_rooted(&x.expr),
+        Expr::Paren(x) => is_rooted_at_self(&x.expr),
+        Expr::Unary(x) if matches!(x.op, UnOp::Deref(_)) => is_rooted_at_self(&x.expr),
+        _ => false,
+    }
+}
+
+fn is_self_or_rooted(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(x) => x.qself.is_none() && x.path.is_ident("self"),
+        _ => is_rooted_at_self(expr),
     }
 }
 
@@ -31,7 +130,7 @@ mod tests {
     use quote::quote;
     use test_case::test_case;
 
-    use crate::model::{FwdDecl, FwdDeclBuilder, MethodBuilder};
+    use crate::model::{Access, Fallback, FwdDecl, FwdDeclBuilder, MethodBuilder};
 
     #[test_case(
         quote!(fn test(self) to self.tester), FwdDeclBuilder::default().named_target("tester").with_method(
@@ -43,6 +142,75 @@ mod tests {
             MethodBuilder::default().ident("test").rcv()
         ); "should parse forwarding with numeric id"
     )]
+    #[test_case(
+        quote!(fn test(self) to self.inner.config.0), FwdDeclBuilder::default()
+            .named_target("inner").named_target("config").unnamed_target(0)
+            .with_method(MethodBuilder::default().ident("test").rcv());
+        "should parse forwarding with nested path"
+    )]
+    #[test_case(
+        quote!(fn test(self) to self.0.1.inner.2), FwdDeclBuilder::default()
+            .unnamed_target(0).unnamed_target(1).named_target("inner").unnamed_target(2)
+            .with_method(MethodBuilder::default().ident("test").rcv());
+        "should parse forwarding with nested numeric ids"
+    )]
+    #[test_case(
+        quote!(fn test(self) to self.inner().get_backend(&self.key)), FwdDeclBuilder::default()
+            .target("self.inner().get_backend(&self.key)")
+            .with_method(MethodBuilder::default().ident("test").rcv());
+        "should parse forwarding with method call target"
+    )]
+    #[test_case(
+        quote!(fn test(self) to (*self.inner).items[1]), FwdDeclBuilder::default()
+            .target("(*self.inner).items[1]")
+            .with_method(MethodBuilder::default().ident("test").rcv());
+        "should parse forwarding with dereferenced and indexed target"
+    )]
+    #[test_case(
+        quote!(fn test(&self) to borrow self.inner), FwdDeclBuilder::default()
+            .access(Access::Borrow).named_target("inner")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv());
+        "should parse forwarding with borrow modifier"
+    )]
+    #[test_case(
+        quote!(fn test(&self) to try_borrow self.inner), FwdDeclBuilder::default()
+            .access(Access::TryBorrow).named_target("inner")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv());
+        "should parse forwarding with try borrow modifier"
+    )]
+    #[test_case(
+        quote!(fn test(&self) to self.inner?), FwdDeclBuilder::default()
+            .access(Access::Optional(None)).named_target("inner")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv());
+        "should parse forwarding to optional target"
+    )]
+    #[test_case(
+        quote!(fn test(&self) to self.inner? else panic("no inner")), FwdDeclBuilder::default()
+            .access(Access::Optional(Some(Fallback::Panic("no inner".to_string()))))
+            .named_target("inner")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv());
+        "should parse forwarding to optional target with fallback"
+    )]
+    #[test_case(
+        quote!(fn find(&self, id: Id) to self.db.find(id, &self.tenant)), FwdDeclBuilder::default()
+            .named_target("db")
+            .with_method(MethodBuilder::default().ident("find").ref_rcv().with_arg("id: Id").template("id, &self.tenant"));
+        "should parse forwarding with call template"
+    )]
+    #[test_case(
+        quote!(fn find_strict as find(&self, id: Id) to self.inner().db.find(Mode::Strict, id)), FwdDeclBuilder::default()
+            .target("self.inner().db")
+            .with_method(MethodBuilder::default().ident("find_strict").inner_ident("find").ref_rcv().with_arg("id: Id")
+                .template("Mode::Strict, id"));
+        "should parse forwarding with call template for renamed method"
+    )]
+    #[test_case(
+        quote!(fn find(&self, id: Id), fn all(&self) to self.db()), FwdDeclBuilder::default()
+            .target("self.db()")
+            .with_method(MethodBuilder::default().ident("find").ref_rcv().with_arg("id: Id"))
+            .with_method(MethodBuilder::default().ident("all").ref_rcv());
+        "should parse method call target for several methods"
+    )]
     fn should_parse_fwd_decl(input: TokenStream, want: &FwdDeclBuilder) {
         let decl = syn::parse2::<FwdDecl>(input).unwrap();
 
@@ -52,7 +220,52 @@ mod tests {
     #[test_case(
         quote!(fn test(self) ot self.tester),
         "malformed delegation: missing 'to' between delegate and target";
-        "should require 'to' between delegate and target" 
+        "should require 'to' between delegate and target"
+    )]
+    #[test_case(
+        quote!(fn test(self) to self),
+        "target must be an expression rooted at self, such as 'self.field' or 'self.method()'";
+        "should require a member or method after self"
+    )]
+    #[test_case(
+        quote!(fn test(self) to other.inner),
+        "target must be an expression rooted at self, such as 'self.field' or 'self.method()'";
+        "should require target rooted at self"
+    )]
+    #[test_case(
+        quote!(fn test(self) to self.inner + 1),
+        "target must be an expression rooted at self, such as 'self.field' or 'self.method()'";
+        "should require target to be a place or method call"
+    )]
+    #[test_case(
+        quote!(fn test(&self) to borrow self.inner?),
+        "optional targets cannot be combined with target modifiers";
+        "should reject optional target with modifier"
+    )]
+    #[test_case(
+        quote!(fn test(&self) to match self),
+        "'match self' targets need the enum variants, derive 'Forward' on the enum instead";
+        "should point enum dispatch to derive"
+    )]
+    #[test_case(
+        quote!(fn find<T>(&self, id: Id) to self.db.find::<T>(id)),
+        "call templates cannot specify generic arguments, declare them on the forwarded method instead";
+        "should reject generic arguments in call template"
+    )]
+    #[test_case(
+        quote!(fn find(&self, id: u64 => Id::new) to self.db.find(id, &self.tenant)),
+        "call templates cannot be combined with argument adapters";
+        "should reject argument adapters with call template"
+    )]
+    #[test_case(
+        quote!(fn find(&self, id: Id), fn all(&self) to self.db.find(id)),
+        "call templates can only forward a single method, declare 'find' in its own 'fwd!'";
+        "should reject call template for several methods"
+    )]
+    #[test_case(
+        quote!(impl Finder for Outer { fn find(&self, id: Id) } to self.db.find(id)),
+        "call templates cannot be used to forward trait methods";
+        "should reject call template for trait methods"
     )]
     fn should_fail_to_parse_fwd_decl(input: TokenStream, want: &str) {
         let err = syn::parse2::<FwdDecl>(input).unwrap_err();
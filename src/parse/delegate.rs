@@ -1,38 +1,206 @@
 use syn::parse::{Parse, ParseStream};
-use syn::{Error, Result, Token};
+use syn::{
+    braced, parse_quote, token, Attribute, Error, Generics, Ident, ImplItem, Path, Result, Token,
+    Type, Visibility,
+};
 
-use crate::model::{Delegate, Method};
+use crate::model::{Delegate, Method, Options, TraitImpl};
+use crate::parse::method::peek_method;
+use crate::parse::parse_where_clause;
+
+const FMT_TRAITS: [&str; 9] = [
+    "Binary", "Debug", "Display", "LowerExp", "LowerHex", "Octal", "Pointer", "UpperExp",
+    "UpperHex",
+];
 
 impl Parse for Delegate {
     fn parse(input: ParseStream) -> Result<Self> {
-        if input.peek(Token![fn]) {
-            let meth: Method = input.parse()?;
-            let mut methods = vec![meth];
-
-            while input.peek(Token![,]) && input.peek2(Token![fn]) {
-                _ = input.parse::<Token![,]>();
-
-                let meth: Method = input.parse()?;
-                methods.push(meth)
-            }
-
-            Ok(Delegate::MethodList(methods))
+        if peek_method(input) {
+            Ok(Delegate::MethodList(parse_method_list(input)?))
+        } else if input.peek(Token![impl]) {
+            Ok(Delegate::Trait(input.parse()?))
         } else {
             Err(Error::new(
                 input.span(),
-                "delegates must be declared as a list of methods in the form 'fn ident(arg1, arg2, ...) -> Return'"
+                "delegates must be declared as a list of methods in the form 'fn ident(arg1, arg2, ...) -> Return' or as a trait in the form 'impl Trait for Type'"
             ))
         }
     }
 }
 
+impl Parse for TraitImpl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        _ = input.parse::<Token![impl]>()?;
+
+        let mut generics: Generics = input.parse()?;
+        let path: Path = input.parse()?;
+        _ = input.parse::<Token![for]>()?;
+        let self_ty: Type = input.parse()?;
+        generics.where_clause = parse_where_clause(input)?;
+
+        let (items, methods) = if input.peek(token::Brace) {
+            let body_buf;
+            _ = braced!(body_buf in input);
+            parse_trait_body(&body_buf)?
+        } else {
+            (Vec::new(), fmt_trait_methods(&path)?)
+        };
+
+        if let Some(meth) = methods
+            .iter()
+            .find(|x| !matches!(x.vis, Visibility::Inherited))
+        {
+            return Err(Error::new_spanned(
+                &meth.vis,
+                "trait methods cannot declare a visibility",
+            ));
+        }
+
+        if let Some(inner_ident) = methods.iter().find_map(|x| x.inner_ident.as_ref()) {
+            return Err(Error::new_spanned(
+                inner_ident,
+                "trait methods cannot be renamed",
+            ));
+        }
+
+        if let Some(arg) = methods
+            .iter()
+            .flat_map(|x| &x.args)
+            .find(|x| x.adapter.is_some())
+        {
+            return Err(Error::new_spanned(
+                &arg.pat,
+                "trait methods cannot adapt arguments",
+            ));
+        }
+
+        Ok(TraitImpl {
+            generics,
+            path,
+            self_ty,
+            items,
+            methods,
+        })
+    }
+}
+
+fn parse_method_list(input: ParseStream) -> Result<Vec<Method>> {
+    let defaults = parse_default_options(input)?;
+
+    let meth: Method = input.parse()?;
+    let mut methods = vec![meth];
+
+    while input.peek(Token![,]) && peek_next_method(input) {
+        _ = input.parse::<Token![,]>();
+
+        let meth: Method = input.parse()?;
+        methods.push(meth)
+    }
+
+    for meth in &mut methods {
+        meth.opts.inherit(&defaults)
+    }
+
+    Ok(methods)
+}
+
+fn parse_trait_body(input: ParseStream) -> Result<(Vec<ImplItem>, Vec<Method>)> {
+    let defaults = parse_default_options(input)?;
+
+    let mut items = Vec::new();
+    let mut methods = Vec::new();
+    while !input.is_empty() {
+        if peek_assoc_item(input) {
+            items.push(input.parse()?);
+            continue;
+        }
+
+        let mut meth: Method = input.parse()?;
+        meth.opts.inherit(&defaults);
+        methods.push(meth);
+
+        if input.peek(Token![,]) {
+            _ = input.parse::<Token![,]>()?;
+        } else if !input.is_empty() && !peek_assoc_item(input) {
+            return Err(input.error("expected ',' between trait methods"));
+        }
+    }
+
+    Ok((items, methods))
+}
+
+fn peek_assoc_item(input: ParseStream) -> bool {
+    let fork = input.fork();
+    if fork.call(Attribute::parse_outer).is_err() {
+        return false;
+    }
+
+    fork.peek(Token![type])
+        || fork.peek(Token![const]) && (fork.peek2(Ident) || fork.peek2(Token![_]))
+}
+
+fn parse_default_options(input: ParseStream) -> Result<Options> {
+    let mut opts = Options::default();
+    for attr in input.call(Attribute::parse_inner)? {
+        if !attr.path().is_ident("fwd") {
+            return Err(Error::new_spanned(
+                attr,
+                "only '#![fwd(...)]' inner attributes are supported in delegates",
+            ));
+        }
+
+        opts.parse_attr(&attr)?;
+    }
+
+    Ok(opts)
+}
+
+fn peek_next_method(input: ParseStream) -> bool {
+    let fork = input.fork();
+    _ = fork.parse::<Token![,]>();
+
+    peek_method(&fork)
+}
+
+fn fmt_trait_methods(path: &Path) -> Result<Vec<Method>> {
+    if is_fmt_trait(path) {
+        Ok(vec![parse_quote!(
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result
+        )])
+    } else {
+        Err(Error::new_spanned(
+            path,
+            "trait methods must be declared in braces in the form '{ fn ident(arg1, arg2, ...) -> Return, ... }'",
+        ))
+    }
+}
+
+fn is_fmt_trait(path: &Path) -> bool {
+    let Some(last) = path.segments.last() else {
+        return false;
+    };
+    let segments: Vec<_> = path.segments.iter().map(|x| x.ident.to_string()).collect();
+    let prefix: Vec<_> = segments[..segments.len() - 1]
+        .iter()
+        .map(String::as_str)
+        .collect();
+    let is_fmt_path = matches!(
+        (path.leading_colon.is_some(), &prefix[..]),
+        (false, []) | (false, ["fmt"]) | (_, ["core" | "std", "fmt"])
+    );
+
+    is_fmt_path
+        && path.segments.iter().all(|x| x.arguments.is_empty())
+        && FMT_TRAITS.iter().any(|x| last.ident == x)
+}
+
 #[cfg(test)]
 mod tests {
     use proc_macro2::TokenStream;
     use quote::quote;
     use test_case::test_case;
 
-    use crate::model::{Delegate, MethodBuilder};
+    use crate::model::{Delegate, MethodBuilder, TraitImplBuilder};
 
     #[test_case(
         quote!(fn test_a(self), fn test_b(self)),
@@ -42,6 +210,114 @@ mod tests {
         ]);
         "should parse delegate method list"
     )]
+    #[test_case(
+        quote!(fn test_a<T>(self, val: T) where T: Clone, fn test_b(self)),
+        Delegate::MethodList(vec![
+            MethodBuilder::default().ident("test_a").generics("<T>").rcv().with_arg("val: T")
+                .where_clause("where T: Clone").build().unwrap(),
+            MethodBuilder::default().ident("test_b").rcv().build().unwrap()
+        ]);
+        "should parse delegate method list with where clause"
+    )]
+    #[test_case(
+        quote!(async fn test_a(self), #[fwd(no_await)] async fn test_b(self)),
+        Delegate::MethodList(vec![
+            MethodBuilder::default().asyncness().ident("test_a").rcv().build().unwrap(),
+            MethodBuilder::default().asyncness().no_await().ident("test_b").rcv().build().unwrap()
+        ]);
+        "should parse delegate async method list"
+    )]
+    #[test_case(
+        quote!(fn test_a(self), const fn test_b(self), unsafe fn test_c(self), extern "C" fn test_d(self)),
+        Delegate::MethodList(vec![
+            MethodBuilder::default().ident("test_a").rcv().build().unwrap(),
+            MethodBuilder::default().constness().ident("test_b").rcv().build().unwrap(),
+            MethodBuilder::default().unsafety().ident("test_c").rcv().build().unwrap(),
+            MethodBuilder::default().abi("extern \"C\"").ident("test_d").rcv().build().unwrap()
+        ]);
+        "should parse delegate method list with qualifiers"
+    )]
+    #[test_case(
+        quote!(pub fn test_a(self), fn test_b(self), pub(super) fn test_c(self)),
+        Delegate::MethodList(vec![
+            MethodBuilder::default().vis("pub").ident("test_a").rcv().build().unwrap(),
+            MethodBuilder::default().ident("test_b").rcv().build().unwrap(),
+            MethodBuilder::default().vis("pub(super)").ident("test_c").rcv().build().unwrap()
+        ]);
+        "should parse delegate method list with mixed visibility"
+    )]
+    #[test_case(
+        quote!(#![fwd(err = from)] fn test_a(&self) -> Result<u8, Error>, #[fwd(err = map_err(Error::new))] fn test_b(&self) -> Result<u8, Error>),
+        Delegate::MethodList(vec![
+            MethodBuilder::default().err_mode("from").ident("test_a").ref_rcv().ret("-> Result<u8, Error>").build().unwrap(),
+            MethodBuilder::default().err_mode("map_err(Error::new)").ident("test_b").ref_rcv().ret("-> Result<u8, Error>")
+                .build().unwrap()
+        ]);
+        "should parse delegate method list with default forwarding options"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer { fn test_a(&self), fn test_b(&mut self, val: u8) -> u8 }),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().path("Tester").self_ty("Outer")
+            .with_method(MethodBuilder::default().ident("test_a").ref_rcv())
+            .with_method(MethodBuilder::default().ident("test_b").ref_mut_rcv().with_arg("val: u8").ret("-> u8"))
+            .build().unwrap()
+        ));
+        "should parse delegate trait"
+    )]
+    #[test_case(
+        quote!(impl<T> Tester<T> for Outer<T> where T: Clone { fn test(&self) }),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().generics("<T>").where_clause("where T: Clone")
+            .path("Tester<T>").self_ty("Outer<T>")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv())
+            .build().unwrap()
+        ));
+        "should parse generic delegate trait"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer { #![fwd(err = context(Error::wrap))] fn test(&self) -> Result<(), Error> }),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().path("Tester").self_ty("Outer")
+            .with_method(MethodBuilder::default().err_mode("context(Error::wrap)").ident("test").ref_rcv().ret("-> Result<(), Error>"))
+            .build().unwrap()
+        ));
+        "should parse delegate trait with default forwarding options"
+    )]
+    #[test_case(
+        quote!(impl<T, U> Tester for Outer<T, U> where T: Clone, U: Copy { fn test(&self) }),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().generics("<T, U>").where_clause("where T: Clone, U: Copy")
+            .path("Tester").self_ty("Outer<T, U>")
+            .with_method(MethodBuilder::default().ident("test").ref_rcv())
+            .build().unwrap()
+        ));
+        "should parse delegate trait with multiple where predicates"
+    )]
+    #[test_case(
+        quote!(impl std::fmt::Display for Outer),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().path("std::fmt::Display").self_ty("Outer")
+            .with_method(MethodBuilder::default().ident("fmt").ref_rcv()
+                .with_arg("f: &mut ::core::fmt::Formatter<'_>").ret("-> ::core::fmt::Result"))
+            .build().unwrap()
+        ));
+        "should parse formatting delegate trait without methods"
+    )]
+    #[test_case(
+        quote!(impl Iterator for Outer { type Item = u8; fn next(&mut self) -> Option<u8> }),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().path("Iterator").self_ty("Outer")
+            .with_item("type Item = u8;")
+            .with_method(MethodBuilder::default().ident("next").ref_mut_rcv().ret("-> Option<u8>"))
+            .build().unwrap()
+        ));
+        "should parse delegate trait with associated type"
+    )]
+    #[test_case(
+        quote!(impl Limits for Outer { const MAX: usize = 8; const fn max(&self) -> usize, fn min(&self) -> usize }),
+        Delegate::Trait(Box::new(TraitImplBuilder::default().path("Limits").self_ty("Outer")
+            .with_item("const MAX: usize = 8;")
+            .with_method(MethodBuilder::default().constness().ident("max").ref_rcv().ret("-> usize"))
+            .with_method(MethodBuilder::default().ident("min").ref_rcv().ret("-> usize"))
+            .build().unwrap()
+        ));
+        "should parse delegate trait with associated const"
+    )]
     fn should_parse_delegate(input: TokenStream, want: Delegate) {
         let del = syn::parse2::<Delegate>(input).unwrap();
 
@@ -50,8 +326,43 @@ mod tests {
 
     #[test_case(
         quote!(invalid),
-        "delegates must be declared as a list of methods in the form 'fn ident(arg1, arg2, ...) -> Return'";
-        "should require method list format"
+        "delegates must be declared as a list of methods in the form 'fn ident(arg1, arg2, ...) -> Return' or as a trait in the form 'impl Trait for Type'";
+        "should require method list or trait format"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer),
+        "trait methods must be declared in braces in the form '{ fn ident(arg1, arg2, ...) -> Return, ... }'";
+        "should require methods for traits other than formatting traits"
+    )]
+    #[test_case(
+        quote!(impl report::Display for Outer),
+        "trait methods must be declared in braces in the form '{ fn ident(arg1, arg2, ...) -> Return, ... }'";
+        "should require methods for traits named like formatting traits outside fmt"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer { fn test_a(&self) fn test_b(&self) }),
+        "expected ',' between trait methods";
+        "should require comma between trait methods"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer { pub fn test(&self) }),
+        "trait methods cannot declare a visibility";
+        "should reject visibility on trait methods"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer { fn test as other(&self) }),
+        "trait methods cannot be renamed";
+        "should reject renamed trait methods"
+    )]
+    #[test_case(
+        quote!(impl Tester for Outer { fn test(&self, name: impl Into<String> => .into()) }),
+        "trait methods cannot adapt arguments";
+        "should reject argument adapters on trait methods"
+    )]
+    #[test_case(
+        quote!(#![allow(unused)] fn test(&self)),
+        "only '#![fwd(...)]' inner attributes are supported in delegates";
+        "should reject unknown inner attributes"
     )]
     fn should_fail_to_parse_delegate(input: TokenStream, want: &str) {
         let err = syn::parse2::<Delegate>(input).unwrap_err();
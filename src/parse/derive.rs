@@ -0,0 +1,203 @@
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    parse_quote, Attribute, Data, DataEnum, DeriveInput, Error, Fields, Member, Result, Variant,
+};
+
+use crate::model::{Access, Delegate, FwdDecl, FwdDerive, Payload};
+
+impl Parse for FwdDerive {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let input: DeriveInput = input.parse()?;
+
+        let decls = match &input.data {
+            Data::Struct(data) => parse_field_decls(&input.attrs, &data.fields)?,
+            Data::Enum(data) => parse_variant_decls(&input.attrs, data)?,
+            Data::Union(_) => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "Forward can only be derived for structs and enums",
+                ))
+            }
+        };
+
+        Ok(FwdDerive {
+            ident: input.ident,
+            generics: input.generics,
+            decls,
+        })
+    }
+}
+
+fn parse_field_decls(attrs: &[Attribute], fields: &Fields) -> Result<Vec<FwdDecl>> {
+    if let Some(attr) = forward_attrs(attrs).next() {
+        return Err(Error::new_spanned(
+            attr,
+            "structs cannot declare forwarding attributes, put them on the field to forward to",
+        ));
+    }
+
+    let mut decls = Vec::new();
+    for (idx, field) in fields.iter().enumerate() {
+        let target = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::from(idx),
+        };
+
+        for attr in forward_attrs(&field.attrs) {
+            let delegate: Delegate = attr.parse_args()?;
+            decls.push(FwdDecl {
+                delegate,
+                target: parse_quote!(self.#target),
+                access: Access::Direct,
+            });
+        }
+    }
+
+    Ok(decls)
+}
+
+fn parse_variant_decls(attrs: &[Attribute], data: &DataEnum) -> Result<Vec<FwdDecl>> {
+    for variant in &data.variants {
+        let nested = variant.fields.iter().flat_map(|x| forward_attrs(&x.attrs));
+        if let Some(attr) = forward_attrs(&variant.attrs).chain(nested).next() {
+            return Err(Error::new_spanned(
+                attr,
+                "enum variants cannot declare forwarding attributes, put them on the enum instead",
+            ));
+        }
+    }
+
+    let mut decls = Vec::new();
+    for attr in forward_attrs(attrs) {
+        let delegate: Delegate = attr.parse_args()?;
+        let payloads = data
+            .variants
+            .iter()
+            .map(parse_payload)
+            .collect::<Result<_>>()?;
+
+        decls.push(FwdDecl {
+            delegate,
+            target: parse_quote!(self),
+            access: Access::Variants(payloads),
+        });
+    }
+
+    Ok(decls)
+}
+
+fn parse_payload(variant: &Variant) -> Result<Payload> {
+    let mut fields = variant.fields.iter();
+    match (fields.next(), fields.next()) {
+        (Some(field), None) => Ok(Payload {
+            variant: variant.ident.clone(),
+            field: field.ident.clone(),
+        }),
+        (None, _) => Err(Error::new_spanned(
+            variant,
+            format!(
+                "enum variant '{}' has no payload to forward to",
+                variant.ident
+            ),
+        )),
+        (Some(_), Some(_)) => Err(Error::new_spanned(
+            variant,
+            format!(
+                "enum variant '{}' must have a single field to forward to",
+                variant.ident
+            ),
+        )),
+    }
+}
+
+fn forward_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|x| x.path().is_ident("forward"))
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use test_case::test_case;
+
+    use crate::model::{FwdDeclBuilder, FwdDerive, FwdDeriveBuilder, MethodBuilder};
+
+    #[test_case(
+        quote!(struct Tester { #[forward(fn test(&self))] inner: Inner }),
+        FwdDeriveBuilder::default().ident("Tester").with_decl(
+            FwdDeclBuilder::default().named_target("inner").with_method(MethodBuilder::default().ident("test").ref_rcv())
+        );
+        "should parse forwarding from named field"
+    )]
+    #[test_case(
+        quote!(struct Tester(Other, #[forward(fn test(&self), fn other(self))] Inner);),
+        FwdDeriveBuilder::default().ident("Tester").with_decl(
+            FwdDeclBuilder::default().unnamed_target(1)
+                .with_method(MethodBuilder::default().ident("test").ref_rcv())
+                .with_method(MethodBuilder::default().ident("other").rcv())
+        );
+        "should parse forwarding from unnamed field"
+    )]
+    #[test_case(
+        quote!(struct Tester<T> { #[forward(fn test(&self))] #[forward(fn other(self))] inner: T }),
+        FwdDeriveBuilder::default().ident("Tester").generics("<T>")
+            .with_decl(FwdDeclBuilder::default().named_target("inner").with_method(MethodBuilder::default().ident("test").ref_rcv()))
+            .with_decl(FwdDeclBuilder::default().named_target("inner").with_method(MethodBuilder::default().ident("other").rcv()));
+        "should parse repeated forwarding attributes on generic struct"
+    )]
+    #[test_case(
+        quote!(struct Tester { ignored: Inner }),
+        FwdDeriveBuilder::default().ident("Tester");
+        "should ignore fields without forwarding attributes"
+    )]
+    #[test_case(
+        quote!(#[forward(fn area(&self) -> f64)] enum Shape { Circle(Circle), Rect { rect: Rect } }),
+        FwdDeriveBuilder::default().ident("Shape").with_decl(
+            FwdDeclBuilder::default().target("self")
+                .with_variant("Circle", None).with_variant("Rect", Some("rect"))
+                .with_method(MethodBuilder::default().ident("area").ref_rcv().ret("-> f64"))
+        );
+        "should parse forwarding to enum variants"
+    )]
+    fn should_parse_fwd_derive(input: TokenStream, want: &FwdDeriveBuilder) {
+        let derive = syn::parse2::<FwdDerive>(input).unwrap();
+
+        assert_eq!(derive, want.build().unwrap())
+    }
+
+    #[test_case(
+        quote!(union Tester { a: u8 }),
+        "Forward can only be derived for structs and enums";
+        "should require a struct or enum"
+    )]
+    #[test_case(
+        quote!(#[forward(fn test(&self))] struct Tester { inner: Inner }),
+        "structs cannot declare forwarding attributes, put them on the field to forward to";
+        "should require forwarding attributes on struct fields"
+    )]
+    #[test_case(
+        quote!(enum Tester { A(#[forward(fn test(&self))] Inner) }),
+        "enum variants cannot declare forwarding attributes, put them on the enum instead";
+        "should require forwarding attributes on enum"
+    )]
+    #[test_case(
+        quote!(#[forward(fn test(&self))] enum Tester { A(Inner), Empty }),
+        "enum variant 'Empty' has no payload to forward to";
+        "should require payload in every variant"
+    )]
+    #[test_case(
+        quote!(#[forward(fn test(&self))] enum Tester { A(Inner, Other) }),
+        "enum variant 'A' must have a single field to forward to";
+        "should require single field in every variant"
+    )]
+    #[test_case(
+        quote!(struct Tester { #[forward(invalid)] inner: Inner }),
+        "delegates must be declared as a list of methods in the form 'fn ident(arg1, arg2, ...) -> Return' or as a trait in the form 'impl Trait for Type'";
+        "should require method list in forwarding attribute"
+    )]
+    fn should_fail_to_parse_fwd_derive(input: TokenStream, want: &str) {
+        let err = syn::parse2::<FwdDerive>(input).unwrap_err();
+
+        assert_eq!(err.to_string(), want)
+    }
+}
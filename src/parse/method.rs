@@ -1,45 +1,144 @@
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::quote;
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
-    Error, FnArg, PatType, Receiver, Result, Token,
+    Attribute, Error, Expr, FnArg, GenericArgument, Generics, PathArguments, Receiver, Result,
+    ReturnType, Token, Type,
 };
 
-use crate::model::Method;
+use crate::model::{Adapter, Arg, Method, Options, RcvKind};
+use crate::parse::parse_where_clause;
 
 impl Parse for Method {
     fn parse(input: ParseStream) -> Result<Self> {
+        let mut opts = Options::default();
+        let mut attrs = Vec::new();
+        for attr in input.call(Attribute::parse_outer)? {
+            if attr.path().is_ident("fwd") {
+                opts.parse_attr(&attr)?
+            } else {
+                attrs.push(attr)
+            }
+        }
+
+        let vis = input.parse()?;
+        let constness = input.parse()?;
+        let asyncness = input.parse()?;
+        let unsafety = input.parse()?;
+        let abi = input.parse()?;
         _ = input.parse::<Token![fn]>()?;
 
         let ident: Ident = input.parse()?;
+        let inner_ident = parse_inner_ident(input)?;
+        let mut generics: Generics = input.parse()?;
         let (rcv, args) = parse_fn_args(input)?;
+        let rcv_kind = parse_rcv_kind(&rcv)?;
         let ret = input.parse()?;
+        let inner_ret = parse_inner_ret(input, &ret)?;
+        let via = parse_via(input, &ret)?;
+        generics.where_clause = parse_where_clause(input)?;
 
         Ok(Method {
+            attrs,
+            opts,
+            vis,
+            constness,
+            asyncness,
+            unsafety,
+            abi,
             ident,
+            inner_ident,
+            generics,
             rcv,
+            rcv_kind,
             args,
             ret,
+            inner_ret,
+            via,
+            template: None,
         })
     }
 }
 
-fn parse_fn_args(input: ParseStream) -> Result<(Receiver, Vec<PatType>)> {
+pub(super) fn peek_method(input: ParseStream) -> bool {
+    input.peek(Token![fn])
+        || input.peek(Token![pub])
+        || input.peek(Token![const])
+        || input.peek(Token![async])
+        || input.peek(Token![unsafe])
+        || input.peek(Token![extern])
+        || input.peek(Token![#])
+}
+
+fn parse_inner_ident(input: ParseStream) -> Result<Option<Ident>> {
+    if input.peek(Token![as]) {
+        _ = input.parse::<Token![as]>()?;
+        Ok(Some(input.parse()?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_inner_ret(input: ParseStream, ret: &ReturnType) -> Result<Option<Box<Type>>> {
+    let fork = input.fork();
+    if !matches!(fork.parse::<Ident>(), Ok(ident) if ident == "from") {
+        return Ok(None);
+    }
+
+    let from: Ident = input.parse()?;
+    if let ReturnType::Default = ret {
+        return Err(Error::new(
+            from.span(),
+            "declaring the inner type with 'from' requires a declared return type",
+        ));
+    }
+
+    Ok(Some(input.parse()?))
+}
+
+fn parse_via(input: ParseStream, ret: &ReturnType) -> Result<Option<Expr>> {
+    let fork = input.fork();
+    if !matches!(fork.parse::<Ident>(), Ok(ident) if ident == "via") {
+        return Ok(None);
+    }
+
+    let via: Ident = input.parse()?;
+    if let ReturnType::Default = ret {
+        return Err(Error::new(
+            via.span(),
+            "mapping with 'via' requires a declared return type",
+        ));
+    }
+
+    Ok(Some(input.parse()?))
+}
+
+fn parse_fn_args(input: ParseStream) -> Result<(Receiver, Vec<Arg>)> {
     let args_buf;
     _ = parenthesized!(args_buf in input);
 
-    let punct_args = args_buf.parse_terminated(FnArg::parse, Token![, ])?;
-    let mut args = punct_args.iter();
+    let rcv = get_receiver(&args_buf)?;
+    let mut args = Vec::new();
+    while args_buf.peek(Token![,]) {
+        _ = args_buf.parse::<Token![,]>()?;
+        if args_buf.is_empty() {
+            break;
+        }
+
+        args.push(args_buf.parse()?);
+    }
+
+    if !args_buf.is_empty() {
+        return Err(args_buf.error("expected ',' between method arguments"));
+    }
 
-    Ok((
-        get_receiver(args.next())?,
-        args.flat_map(select_pat_type).collect(),
-    ))
+    Ok((rcv, args))
 }
 
-fn get_receiver(arg: Option<&FnArg>) -> Result<Receiver> {
-    if let Some(FnArg::Receiver(rcv)) = arg {
-        Ok(rcv.clone())
+fn get_receiver(input: ParseStream) -> Result<Receiver> {
+    if let Ok(FnArg::Receiver(rcv)) = input.parse() {
+        Ok(rcv)
     } else {
         Err(Error::new(
             Span::call_site(),
@@ -48,11 +147,105 @@ fn get_receiver(arg: Option<&FnArg>) -> Result<Receiver> {
     }
 }
 
-fn select_pat_type(arg: &FnArg) -> Option<PatType> {
-    if let FnArg::Typed(pt) = arg.clone() {
-        Some(pt)
-    } else {
-        None
+fn parse_rcv_kind(rcv: &Receiver) -> Result<RcvKind> {
+    rcv_kind_of(&rcv.ty).ok_or_else(|| {
+        Error::new_spanned(
+            &rcv.ty,
+            "unsupported receiver type, expected one of 'Self', '&Self', '&mut Self', 'Box<Self>', 'Rc<Self>', 'Arc<Self>', 'Pin<&Self>' or 'Pin<&mut Self>'",
+        )
+    })
+}
+
+fn rcv_kind_of(ty: &Type) -> Option<RcvKind> {
+    match ty {
+        Type::Reference(x) if is_self_ty(&x.elem) && x.mutability.is_some() => {
+            Some(RcvKind::RefMut)
+        }
+        Type::Reference(x) if is_self_ty(&x.elem) => Some(RcvKind::Ref),
+        Type::Path(x) if x.qself.is_none() && x.path.is_ident("Self") => Some(RcvKind::Value),
+        Type::Path(x) if x.qself.is_none() => {
+            let segment = x.path.segments.last()?;
+            let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+                return None;
+            };
+            let [GenericArgument::Type(arg)] = Vec::from_iter(&generics.args)[..] else {
+                return None;
+            };
+
+            match (segment.ident.to_string().as_str(), rcv_kind_of(arg)?) {
+                ("Box", RcvKind::Value) => Some(RcvKind::Boxed),
+                ("Rc" | "Arc", RcvKind::Value) => Some(RcvKind::Shared),
+                ("Pin", RcvKind::Ref) => Some(RcvKind::PinRef),
+                ("Pin", RcvKind::RefMut) => Some(RcvKind::PinMut),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_self_ty(ty: &Type) -> bool {
+    matches!(ty, Type::Path(x) if x.qself.is_none() && x.path.is_ident("Self"))
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let pt = match input.parse()? {
+            FnArg::Typed(pt) => pt,
+            FnArg::Receiver(rcv) => {
+                return Err(Error::new_spanned(
+                    rcv,
+                    "only the first argument can be a receiver",
+                ))
+            }
+        };
+
+        let adapter = if input.peek(Token![=>]) {
+            _ = input.parse::<Token![=>]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Arg {
+            attrs: pt.attrs,
+            pat: pt.pat,
+            ty: pt.ty,
+            adapter,
+        })
+    }
+}
+
+impl Parse for Adapter {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if !input.peek(Token![.]) {
+            return Ok(Adapter::Func(input.parse()?));
+        }
+
+        let mut chain = TokenStream::new();
+        while !input.is_empty() && !input.peek(Token![,]) {
+            chain.extend([input.parse::<TokenTree>()?]);
+        }
+
+        if !syn::parse2::<Expr>(quote!(arg #chain)).is_ok_and(|x| is_chained_from_arg(&x)) {
+            return Err(Error::new_spanned(
+                chain,
+                "argument adapters must be a method chain such as '.into()' or a path such as 'path::func'",
+            ));
+        }
+
+        Ok(Adapter::Chain(chain))
+    }
+}
+
+fn is_chained_from_arg(expr: &Expr) -> bool {
+    match expr {
+        Expr::MethodCall(x) => is_chained_from_arg(&x.receiver),
+        Expr::Field(x) => is_chained_from_arg(&x.base),
+        Expr::Try(x) => is_chained_from_arg(&x.expr),
+        Expr::Await(x) => is_chained_from_arg(&x.base),
+        Expr::Path(x) => x.path.is_ident("arg"),
+        _ => false,
     }
 }
 
@@ -62,7 +255,7 @@ mod test {
     use quote::quote;
     use test_case::test_case;
 
-    use crate::model::{Method, MethodBuilder};
+    use crate::model::{Method, MethodBuilder, RcvKind, RetMode};
 
     #[test_case(
         quote!(fn test(self)),
@@ -99,6 +292,144 @@ mod test {
         MethodBuilder::default().ident("test").rcv().ret("-> (String, uint)");
         "should parse method with tuple return value"
     )]
+    #[test_case(
+        quote!(fn test<T: FromStr, const N: usize>(&self, val: [T; N]) -> Option<T>),
+        MethodBuilder::default().ident("test").generics("<T: FromStr, const N: usize>").ref_rcv()
+            .with_arg("val: [T; N]").ret("-> Option<T>");
+        "should parse method with generic parameters"
+    )]
+    #[test_case(
+        quote!(fn test<'a>(&'a self, key: &'a str) -> Option<&'a String>),
+        MethodBuilder::default().ident("test").generics("<'a>").lifetime_ref_rcv("'a")
+            .with_arg("key: &'a str").ret("-> Option<&'a String>");
+        "should parse method with lifetime parameters"
+    )]
+    #[test_case(
+        quote!(fn test<'a>(&'a mut self) -> &'a mut String),
+        MethodBuilder::default().ident("test").generics("<'a>").lifetime_ref_mut_rcv("'a").ret("-> &'a mut String");
+        "should parse method with named mut ref receiver lifetime"
+    )]
+    #[test_case(
+        quote!(async fn test(&self, id: u64) -> String),
+        MethodBuilder::default().asyncness().ident("test").ref_rcv().with_arg("id: u64").ret("-> String");
+        "should parse async method"
+    )]
+    #[test_case(
+        quote!(#[fwd(no_await)] async fn test(&self)),
+        MethodBuilder::default().asyncness().no_await().ident("test").ref_rcv();
+        "should parse async method returning future"
+    )]
+    #[test_case(
+        quote!(#[fwd(ret = to_owned)] fn test(&self) -> String),
+        MethodBuilder::default().ret_mode(RetMode::ToOwned).ident("test").ref_rcv().ret("-> String");
+        "should parse method with return mode"
+    )]
+    #[test_case(
+        quote!(#[fwd(no_await, ret = into)] async fn test(&self) -> u64),
+        MethodBuilder::default().no_await().ret_mode(RetMode::Into).asyncness().ident("test").ref_rcv().ret("-> u64");
+        "should parse method with several forwarding options"
+    )]
+    #[test_case(
+        quote!(#[fwd(err = map_err(Error::Store))] fn test(&self) -> Result<u8, Error>),
+        MethodBuilder::default().err_mode("map_err(Error::Store)").ident("test").ref_rcv().ret("-> Result<u8, Error>");
+        "should parse method with error conversion"
+    )]
+    #[test_case(
+        quote!(const fn test(&self) -> usize),
+        MethodBuilder::default().constness().ident("test").ref_rcv().ret("-> usize");
+        "should parse const method"
+    )]
+    #[test_case(
+        quote!(unsafe fn test(&self, idx: usize) -> &u8),
+        MethodBuilder::default().unsafety().ident("test").ref_rcv().with_arg("idx: usize").ret("-> &u8");
+        "should parse unsafe method"
+    )]
+    #[test_case(
+        quote!(const unsafe extern "C" fn test(&self)),
+        MethodBuilder::default().constness().unsafety().abi("extern \"C\"").ident("test").ref_rcv();
+        "should parse method with all qualifiers"
+    )]
+    #[test_case(
+        quote!(#[inline] #[must_use] fn test(&self) -> usize),
+        MethodBuilder::default().with_attr("#[inline]").with_attr("#[must_use]").ident("test").ref_rcv().ret("-> usize");
+        "should parse method with attributes"
+    )]
+    #[test_case(
+        quote!(pub fn test(&self)),
+        MethodBuilder::default().vis("pub").ident("test").ref_rcv();
+        "should parse public method"
+    )]
+    #[test_case(
+        quote!(#[inline] pub(crate) const fn test(&self)),
+        MethodBuilder::default().with_attr("#[inline]").vis("pub(crate)").constness().ident("test").ref_rcv();
+        "should parse restricted visibility method"
+    )]
+    #[test_case(
+        quote!(fn message_len as len<T>(&self) -> usize),
+        MethodBuilder::default().ident("message_len").inner_ident("len").generics("<T>").ref_rcv().ret("-> usize");
+        "should parse renamed method"
+    )]
+    #[test_case(
+        quote!(
+            /// Tests things
+            #[fwd(no_await)]
+            #[cfg(test)]
+            async fn test(&self)
+        ),
+        MethodBuilder::default().with_attr("#[doc = r\" Tests things\"]").with_attr("#[cfg(test)]")
+            .no_await().asyncness().ident("test").ref_rcv();
+        "should parse method with doc comment and forwarding options"
+    )]
+    #[test_case(
+        quote!(fn test<F>(&mut self, f: F) -> usize where F: FnMut(&u8) -> bool, Self: Sized),
+        MethodBuilder::default().ident("test").generics("<F>").ref_mut_rcv().with_arg("f: F").ret("-> usize")
+            .where_clause("where F: FnMut(&u8) -> bool, Self: Sized");
+        "should parse method with where clause"
+    )]
+    #[test_case(
+        quote!(fn test(&self) -> OuterId via OuterId::from),
+        MethodBuilder::default().ident("test").ref_rcv().ret("-> OuterId").via("OuterId::from");
+        "should parse method with return value mapping path"
+    )]
+    #[test_case(
+        quote!(#[fwd(err = from)] fn test(&self) -> Result<u8, Error> from io::Result<u8>),
+        MethodBuilder::default().err_mode("from").ident("test").ref_rcv().ret("-> Result<u8, Error>")
+            .inner_ret("io::Result<u8>");
+        "should parse method with declared inner return type"
+    )]
+    #[test_case(
+        quote!(fn test<T>(&self) -> Vec<T> via |x| x.map(T::from).collect() where T: From<u8>),
+        MethodBuilder::default().ident("test").generics("<T>").ref_rcv().ret("-> Vec<T>")
+            .via("|x| x.map(T::from).collect()").where_clause("where T: From<u8>");
+        "should parse method with return value mapping closure and where clause"
+    )]
+    #[test_case(
+        quote!(fn test(&self, name: impl Into<String> => .into(), path: impl AsRef<Path> => .as_ref(), id: u64 => Id::new,)),
+        MethodBuilder::default().ident("test").ref_rcv().with_arg("name: impl Into<String> => .into()")
+            .with_arg("path: impl AsRef<Path> => .as_ref()").with_arg("id: u64 => Id::new");
+        "should parse method with argument adapters"
+    )]
+    #[test_case(
+        quote!(fn test(self: &mut Self)),
+        MethodBuilder::default().ident("test").typed_rcv("self: &mut Self", RcvKind::RefMut);
+        "should parse method with typed mutable reference receiver"
+    )]
+    #[test_case(
+        quote!(fn test(self: Box<Self>) -> String),
+        MethodBuilder::default().ident("test").typed_rcv("self: Box<Self>", RcvKind::Boxed).ret("-> String");
+        "should parse method with boxed receiver"
+    )]
+    #[test_case(
+        quote!(fn test(self: std::sync::Arc<Self>)),
+        MethodBuilder::default().ident("test").typed_rcv("self: std::sync::Arc<Self>", RcvKind::Shared);
+        "should parse method with reference counted receiver"
+    )]
+    #[test_case(
+        quote!(fn test(self: Pin<&mut Self>, cx: &mut Context<'_>)),
+        MethodBuilder::default().ident("test").typed_rcv("self: Pin<&mut Self>", RcvKind::PinMut)
+            .with_arg("cx: &mut Context<'_>");
+        "should parse method with pinned receiver"
+    )]
     fn should_parse_method(input: TokenStream, want: &mut MethodBuilder) {
         let meth = syn::parse2::<Method>(input).unwrap();
 
@@ -106,6 +437,46 @@ mod test {
     }
 
     #[test_case(quote!(fn test()), "method must have a receiver to be forwarded"; "should require a receiver")]
+    #[test_case(
+        quote!(#[fwd(unknown)] fn test(&self)),
+        "unsupported forwarding option";
+        "should require known forwarding options"
+    )]
+    #[test_case(
+        quote!(#[fwd(ret = borrow)] fn test(&self)),
+        "unsupported return mode, expected one of 'as_is', 'clone', 'copy', 'to_owned', 'into' or 'auto'";
+        "should require known return mode"
+    )]
+    #[test_case(
+        quote!(#[fwd(err = wrap)] fn test(&self) -> Result<u8, Error>),
+        "unsupported error conversion, expected one of 'from', 'map_err(path)' or 'context(path)'";
+        "should require known error conversion"
+    )]
+    #[test_case(
+        quote!(fn test(&self) via OuterId::from),
+        "mapping with 'via' requires a declared return type";
+        "should require return type for return value mapping"
+    )]
+    #[test_case(
+        quote!(fn test(&self) from io::Result<u8>),
+        "declaring the inner type with 'from' requires a declared return type";
+        "should require return type for declared inner type"
+    )]
+    #[test_case(
+        quote!(fn test(&self, name: impl Into<String> => .into() + 1)),
+        "argument adapters must be a method chain such as '.into()' or a path such as 'path::func'";
+        "should require method chain in argument adapter"
+    )]
+    #[test_case(
+        quote!(fn test(&self, name: String, &self)),
+        "only the first argument can be a receiver";
+        "should require receiver to be first argument"
+    )]
+    #[test_case(
+        quote!(fn test(self: Pin<Box<Self>>)),
+        "unsupported receiver type, expected one of 'Self', '&Self', '&mut Self', 'Box<Self>', 'Rc<Self>', 'Arc<Self>', 'Pin<&Self>' or 'Pin<&mut Self>'";
+        "should require supported receiver type"
+    )]
     fn should_fail_to_parse_method(input: TokenStream, want: &str) {
         let err = syn::parse2::<Method>(input).unwrap_err();
 
@@ -1,3 +1,33 @@
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::{Result, Token, WhereClause, WherePredicate};
+
+mod access;
 mod decl;
 mod delegate;
+mod derive;
 mod method;
+mod options;
+
+fn parse_where_clause(input: ParseStream) -> Result<Option<WhereClause>> {
+    if !input.peek(Token![where]) {
+        return Ok(None);
+    }
+
+    let where_token = input.parse()?;
+    let mut predicates = Punctuated::new();
+    loop {
+        predicates.push_value(input.parse()?);
+
+        let fork = input.fork();
+        if fork.parse::<Token![,]>().is_err() || fork.parse::<WherePredicate>().is_err() {
+            break;
+        }
+        predicates.push_punct(input.parse()?);
+    }
+
+    Ok(Some(WhereClause {
+        where_token,
+        predicates,
+    }))
+}
@@ -0,0 +1,71 @@
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, Attribute, Error, Ident, Path, Result};
+
+use crate::model::{ErrMode, Options, RetMode};
+
+impl Options {
+    pub fn parse_attr(&mut self, attr: &Attribute) -> Result<()> {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("no_await") {
+                self.no_await = true;
+                Ok(())
+            } else if meta.path.is_ident("ret") {
+                let mode: Ident = meta.value()?.parse()?;
+                self.ret = Some(parse_ret_mode(&mode)?);
+                Ok(())
+            } else if meta.path.is_ident("err") {
+                self.err = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported forwarding option"))
+            }
+        })
+    }
+
+    pub fn inherit(&mut self, defaults: &Options) {
+        self.no_await |= defaults.no_await;
+        if self.ret.is_none() {
+            self.ret = defaults.ret;
+        }
+        if self.err.is_none() {
+            self.err = defaults.err.clone();
+        }
+    }
+}
+
+impl Parse for ErrMode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mode: Ident = input.parse()?;
+        match mode.to_string().as_str() {
+            "from" => Ok(ErrMode::From),
+            "map_err" => Ok(ErrMode::MapErr(parse_err_path(input)?)),
+            "context" => Ok(ErrMode::Context(parse_err_path(input)?)),
+            _ => Err(Error::new(
+                mode.span(),
+                "unsupported error conversion, expected one of 'from', 'map_err(path)' or 'context(path)'",
+            )),
+        }
+    }
+}
+
+fn parse_err_path(input: ParseStream) -> Result<Path> {
+    let path_buf;
+    _ = parenthesized!(path_buf in input);
+
+    path_buf.parse()
+}
+
+fn parse_ret_mode(mode: &Ident) -> Result<RetMode> {
+    match mode.to_string().as_str() {
+        "as_is" => Ok(RetMode::AsIs),
+        "clone" => Ok(RetMode::Clone),
+        "copy" => Ok(RetMode::Copy),
+        "to_owned" => Ok(RetMode::ToOwned),
+        "into" => Ok(RetMode::Into),
+        "auto" => Ok(RetMode::Auto),
+        _ => Err(Error::new(
+            mode.span(),
+            "unsupported return mode, expected one of 'as_is', 'clone', 'copy', 'to_owned', 'into' or 'auto'",
+        )),
+    }
+}
@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+use forward_methods::fwd;
+
+#[derive(Debug, PartialEq)]
+struct Id(u64);
+
+impl Id {
+    fn new(id: u64) -> Self {
+        Id(id)
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    names: Vec<String>,
+    paths: Vec<PathBuf>,
+    ids: Vec<Id>,
+}
+
+impl Registry {
+    fn add_name(&mut self, name: String) {
+        self.names.push(name)
+    }
+
+    fn add_path(&mut self, path: &Path) {
+        self.paths.push(path.to_path_buf())
+    }
+
+    fn add_id(&mut self, id: Id) {
+        self.ids.push(id)
+    }
+}
+
+#[derive(Default)]
+struct Catalog {
+    registry: Registry,
+}
+
+impl Catalog {
+    fwd!(
+        fn add_name(&mut self, name: impl Into<String> => .into()),
+        fn add_path(&mut self, path: impl AsRef<Path> => .as_ref()),
+        fn add_id(&mut self, id: u64 => Id::new)
+        to self.registry
+    );
+}
+
+#[test]
+fn should_adapt_arguments_before_forwarding() {
+    let mut catalog = Catalog::default();
+
+    catalog.add_name("alpha");
+    catalog.add_path("/tmp/alpha");
+    catalog.add_id(7);
+
+    assert_eq!(catalog.registry.names, vec!["alpha"]);
+    assert_eq!(catalog.registry.paths, vec![PathBuf::from("/tmp/alpha")]);
+    assert_eq!(catalog.registry.ids, vec![Id(7)]);
+}
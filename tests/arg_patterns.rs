@@ -0,0 +1,55 @@
+use forward_methods::fwd;
+
+struct Token;
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Default)]
+struct Canvas {
+    bytes: Vec<u8>,
+    origin: (i32, i32),
+}
+
+impl Canvas {
+    fn set(&mut self, mut bytes: Vec<u8>) {
+        bytes.reverse();
+        self.bytes = bytes
+    }
+
+    fn move_to(&mut self, (x, y): (i32, i32), _: Token) {
+        self.origin = (x, y)
+    }
+
+    fn shift(&mut self, Point { x, y }: Point) {
+        self.origin = (self.origin.0 + x, self.origin.1 + y)
+    }
+}
+
+#[derive(Default)]
+struct Window {
+    canvas: Canvas,
+}
+
+impl Window {
+    fwd!(
+        fn set(&mut self, mut bytes: Vec<u8>),
+        fn move_to(&mut self, (x, y): (i32, i32), _: Token),
+        fn shift(&mut self, Point { x, y }: Point)
+        to self.canvas
+    );
+}
+
+#[test]
+fn should_forward_arguments_with_patterns() {
+    let mut window = Window::default();
+
+    window.set(vec![1, 2]);
+    window.move_to((1, 2), Token);
+    window.shift(Point { x: 1, y: 1 });
+
+    assert_eq!(window.canvas.bytes, vec![2, 1]);
+    assert_eq!(window.canvas.origin, (2, 3));
+}
@@ -0,0 +1,79 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use forward_methods::fwd;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Item(u64);
+
+struct Client;
+
+struct Service {
+    client: Client,
+}
+
+impl Client {
+    async fn fetch(&self, id: u64) -> Item {
+        Item(id)
+    }
+
+    async fn find(&self, id: u64) -> Option<Item> {
+        Some(Item(id))
+    }
+
+    async fn lookup(&self, key: &str) -> usize {
+        key.len()
+    }
+
+    async fn matches(&self, key: &str, check: impl Fn(&str) -> bool) -> bool {
+        check(key)
+    }
+
+    async fn close(self) {}
+}
+
+trait Store {
+    async fn lookup(&self, key: &str) -> usize;
+}
+
+impl Store for Client {
+    async fn lookup(&self, key: &str) -> usize {
+        key.len() * 2
+    }
+}
+
+impl Service {
+    fwd!(
+        async fn fetch(&self, id: u64) -> Item,
+        #[fwd(no_await)] async fn find(&self, id: u64) -> Option<Item>,
+        #[fwd(no_await)] async fn lookup(&self, key: &str) -> usize,
+        #[fwd(no_await)] async fn matches(&self, key: &str, check: impl Fn(&str) -> bool) -> bool,
+        async fn close(self)
+        to self.client
+    );
+}
+
+fwd!(impl Store for Service { #[fwd(no_await)] async fn lookup(&self, key: &str) -> usize } to self.client);
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+#[test]
+fn should_forward_async_methods() {
+    let service = Service { client: Client };
+
+    assert_eq!(block_on(service.fetch(1)), Item(1));
+    assert_eq!(block_on(service.find(2)), Some(Item(2)));
+    assert_eq!(block_on(service.lookup(&String::from("key"))), 3);
+    assert_eq!(block_on(Store::lookup(&service, "key")), 6);
+    assert!(block_on(service.matches("key", |x| x.starts_with('k'))));
+    block_on(service.close());
+}
@@ -0,0 +1,53 @@
+#![deny(missing_docs)]
+//! Forwarded methods keep their attributes and documentation.
+
+use forward_methods::fwd_pub;
+
+/// A wrapped message.
+pub struct Message(String);
+
+/// A composite with documented forwards.
+pub struct Wrapper {
+    message: Message,
+}
+
+impl Message {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[allow(dead_code)]
+    fn removed(&self) -> usize {
+        0
+    }
+}
+
+impl Wrapper {
+    fwd_pub!(
+        /// Returns the length of the message.
+        #[inline]
+        #[must_use]
+        fn len(&self) -> usize,
+        /// Returns whether the message is empty.
+        #[cfg(test)]
+        fn is_empty(&self) -> bool,
+        /// Never compiled.
+        #[cfg(not(test))]
+        fn removed(&self) -> usize
+        to self.message
+    );
+}
+
+#[test]
+fn should_forward_methods_with_attributes() {
+    let wrapper = Wrapper {
+        message: Message("hello".to_string()),
+    };
+
+    assert_eq!(wrapper.len(), 5);
+    assert!(!wrapper.is_empty());
+}
@@ -0,0 +1,52 @@
+use forward_methods::fwd;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Strict,
+    Lenient,
+}
+
+struct Db {
+    rows: Vec<(String, u64, Mode)>,
+}
+
+impl Db {
+    fn find(&self, tenant: &str, id: u64, mode: Mode) -> Option<(String, u64, Mode)> {
+        self.rows
+            .iter()
+            .find(|row| row.0 == tenant && row.1 == id)
+            .map(|row| (row.0.clone(), row.1, mode))
+    }
+
+    fn insert(&mut self, tenant: &str, id: u64, mode: Mode) {
+        self.rows.push((tenant.to_string(), id, mode))
+    }
+}
+
+struct Repository {
+    db: Db,
+    tenant: String,
+}
+
+impl Repository {
+    fwd!(fn find(&self, id: u64) -> Option<(String, u64, Mode)> to self.db.find(&self.tenant, id, Mode::Strict));
+    fwd!(fn insert(&mut self, mode: Mode, id: u64) to self.db.insert("shared", id, mode));
+    fwd!(fn find_lenient as find(&self, (tenant, id): (&str, u64)) -> Option<(String, u64, Mode)> to self.db.find(tenant, id, Mode::Lenient));
+}
+
+#[test]
+fn should_forward_with_call_templates() {
+    let mut repo = Repository {
+        db: Db { rows: Vec::new() },
+        tenant: "shared".to_string(),
+    };
+
+    repo.insert(Mode::Lenient, 1);
+
+    assert_eq!(repo.find(1), Some(("shared".to_string(), 1, Mode::Strict)));
+    assert_eq!(repo.find(2), None);
+    assert_eq!(
+        repo.find_lenient(("shared", 1)),
+        Some(("shared".to_string(), 1, Mode::Lenient))
+    );
+}
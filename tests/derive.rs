@@ -0,0 +1,51 @@
+use forward_methods::Forward;
+
+use crate::stubs::{Message, Printer};
+
+mod stubs;
+
+#[derive(Forward)]
+struct CompositeStruct {
+    #[forward(fn get_message(&self) -> String, fn get_len(&self) -> usize)]
+    message: Message,
+    #[forward(fn println(&self, msg: impl Into<String>))]
+    printer: Printer,
+}
+
+#[derive(Forward)]
+struct CompositeTupleStruct(
+    #[forward(fn get_message(&self) -> String, fn get_len(&self) -> usize)] Message,
+    #[forward(fn println(&self, msg: impl Into<String>))] Printer,
+);
+
+#[derive(Forward)]
+struct GenericStruct<T: AsRef<str>> {
+    #[forward(fn as_ref(&self) -> &str)]
+    inner: T,
+}
+
+#[test]
+fn should_forward_methods() {
+    let cmp = CompositeStruct {
+        message: Message("hello, world!".to_string()),
+        printer: Printer,
+    };
+
+    cmp.println(format!("{}: {}", cmp.get_message(), cmp.get_len()))
+}
+
+#[test]
+fn should_forward_methods_from_tuple_struct() {
+    let cmp = CompositeTupleStruct(Message("hello, world!".to_string()), Printer);
+
+    cmp.println(format!("{}: {}", cmp.get_message(), cmp.get_len()))
+}
+
+#[test]
+fn should_forward_methods_from_generic_struct() {
+    let cmp = GenericStruct {
+        inner: "hello, world!",
+    };
+
+    assert_eq!(cmp.as_ref(), "hello, world!")
+}
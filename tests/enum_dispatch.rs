@@ -0,0 +1,78 @@
+use std::fmt;
+
+use forward_methods::Forward;
+
+struct Circle {
+    radius: f64,
+}
+
+impl Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.radius * self.radius
+    }
+
+    fn scale(&mut self, by: f64) {
+        self.radius *= by
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "circle({})", self.radius)
+    }
+}
+
+struct Rect {
+    width: f64,
+    height: f64,
+}
+
+impl Rect {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn scale(&mut self, by: f64) {
+        self.width *= by;
+        self.height *= by
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rect({}x{})", self.width, self.height)
+    }
+}
+
+#[derive(Forward)]
+#[forward(fn area(&self) -> f64, fn scale(&mut self, by: f64))]
+#[forward(impl fmt::Display for Shape)]
+enum Shape {
+    Circle(Circle),
+    Rect { rect: Rect },
+}
+
+#[test]
+fn should_dispatch_to_tuple_variant() {
+    let mut shape = Shape::Circle(Circle { radius: 1.0 });
+
+    shape.scale(2.0);
+
+    assert_eq!(shape.area(), 12.0);
+    assert_eq!(shape.to_string(), "circle(2)");
+}
+
+#[test]
+fn should_dispatch_to_struct_variant() {
+    let mut shape = Shape::Rect {
+        rect: Rect {
+            width: 2.0,
+            height: 3.0,
+        },
+    };
+
+    shape.scale(2.0);
+
+    assert_eq!(shape.area(), 24.0);
+    assert_eq!(shape.to_string(), "rect(4x6)");
+}
@@ -0,0 +1,102 @@
+use forward_methods::{fwd, Forward};
+
+#[derive(Debug, PartialEq)]
+struct DbError(String);
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    Db(DbError),
+    Forwarded(&'static str, DbError),
+}
+
+impl From<DbError> for AppError {
+    fn from(err: DbError) -> Self {
+        AppError::Db(err)
+    }
+}
+
+impl AppError {
+    fn forwarded(context: &'static str, err: DbError) -> Self {
+        AppError::Forwarded(context, err)
+    }
+}
+
+struct Db;
+
+impl Db {
+    fn find(&self, id: u64) -> Result<u64, DbError> {
+        if id == 0 {
+            Err(DbError("missing".to_string()))
+        } else {
+            Ok(id)
+        }
+    }
+
+    fn delete(&mut self, id: u64) -> Result<(), DbError> {
+        self.find(id).map(|_| ())
+    }
+}
+
+struct Service {
+    db: Db,
+}
+
+impl Service {
+    fwd!(
+        #![fwd(err = from)]
+        fn find(&self, id: u64) -> Result<u64, AppError>,
+        fn delete(&mut self, id: u64) -> Result<(), AppError>,
+        fn find_declared as find(&self, id: u64) -> Result<u64, AppError> from Result<u64, DbError>,
+        #[fwd(err = map_err(AppError::Db))]
+        fn find_mapped as find(&self, id: u64) -> Result<u64, AppError>,
+        #[fwd(err = context(AppError::forwarded))]
+        fn find_with_context as find(&self, id: u64) -> Result<u64, AppError>
+        to self.db
+    );
+}
+
+#[derive(Forward)]
+struct DerivedService {
+    #[forward(#![fwd(err = from)] fn find(&self, id: u64) -> Result<u64, AppError>)]
+    db: Db,
+}
+
+#[test]
+fn should_convert_errors_with_from() {
+    let mut service = Service { db: Db };
+
+    assert_eq!(service.find(1), Ok(1));
+    assert_eq!(service.delete(1), Ok(()));
+    assert_eq!(service.find_declared(1), Ok(1));
+    assert_eq!(
+        service.find(0),
+        Err(AppError::Db(DbError("missing".to_string())))
+    );
+    assert_eq!(
+        DerivedService { db: Db }.find(0),
+        Err(AppError::Db(DbError("missing".to_string())))
+    );
+}
+
+#[test]
+fn should_convert_errors_with_map_err() {
+    let service = Service { db: Db };
+
+    assert_eq!(
+        service.find_mapped(0),
+        Err(AppError::Db(DbError("missing".to_string())))
+    );
+}
+
+#[test]
+fn should_convert_errors_with_context() {
+    let service = Service { db: Db };
+
+    assert_eq!(
+        service.find_with_context(0),
+        Err(AppError::Forwarded(
+            "find_with_context forwarded to self.db",
+            DbError("missing".to_string())
+        ))
+    );
+}
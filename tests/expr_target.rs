@@ -0,0 +1,56 @@
+use std::ops::Deref;
+
+use forward_methods::fwd;
+
+struct Message(String);
+
+struct Backends {
+    messages: Vec<Message>,
+}
+
+struct Service {
+    backends: Backends,
+    boxed: Box<Message>,
+}
+
+impl Message {
+    fn get_message(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn get_len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Backends {
+    fn get_backend(&self, idx: usize) -> &Message {
+        &self.messages[idx]
+    }
+}
+
+impl Service {
+    fn backends(&self) -> &Backends {
+        &self.backends
+    }
+
+    fwd!(fn get_message(&self) -> String to self.backends().get_backend(0));
+    fwd!(fn last_len as get_len(&self) -> usize to self.backends.messages[1]);
+    fwd!(fn boxed_len as get_len(&self) -> usize to self.boxed.deref());
+    fwd!(fn deref_len as get_len(&self) -> usize to (*self.boxed));
+}
+
+#[test]
+fn should_forward_methods_to_expressions() {
+    let service = Service {
+        backends: Backends {
+            messages: vec![Message("first".to_string()), Message("second".to_string())],
+        },
+        boxed: Box::new(Message("boxed".to_string())),
+    };
+
+    assert_eq!(service.get_message(), "first");
+    assert_eq!(service.last_len(), 6);
+    assert_eq!(service.boxed_len(), 5);
+    assert_eq!(service.deref_len(), 5);
+}
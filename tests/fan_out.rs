@@ -0,0 +1,134 @@
+use forward_methods::fwd;
+
+#[derive(Default)]
+struct Sink {
+    name: String,
+    pending: usize,
+    broken: bool,
+}
+
+impl Sink {
+    fn new(name: &str, pending: usize) -> Self {
+        Sink {
+            name: name.to_string(),
+            pending,
+            broken: false,
+        }
+    }
+
+    fn flush(&mut self) {
+        self.pending = 0
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn pending(&self) -> usize {
+        self.pending
+    }
+
+    fn is_idle(&self) -> bool {
+        self.pending == 0
+    }
+
+    fn lookup(&self, name: &str) -> Option<usize> {
+        (self.name == name).then_some(self.pending)
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.broken {
+            Err(self.name.clone())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn drain(&mut self) -> Result<usize, String> {
+        self.check()?;
+        Ok(std::mem::take(&mut self.pending))
+    }
+}
+
+struct Broadcaster {
+    sinks: Vec<Sink>,
+}
+
+impl Broadcaster {
+    fwd!(fn flush(&mut self) to each self.sinks);
+    fwd!(fn names as name(&self) -> String to each(vec) self.sinks);
+    fwd!(fn is_idle(&self) -> bool to each(all) self.sinks);
+    fwd!(fn is_busy as is_idle(&self) -> bool to each(any) self.sinks);
+    fwd!(fn lookup(&self, name: &str) -> Option<usize> to each(first) self.sinks);
+    fwd!(fn pending(&self) -> usize to each(sum) self.sinks);
+    fwd!(fn check(&self) -> Result<(), String> to each(result) self.sinks);
+    fwd!(fn drain(&mut self) -> Result<usize, String> to each(result) self.sinks);
+}
+
+struct Pair {
+    sinks: [Sink; 2],
+}
+
+impl Pair {
+    fwd!(fn flush(&mut self) to each self.sinks);
+    fwd!(fn pending(&self) -> usize to each(sum) self.sinks);
+}
+
+#[test]
+fn should_fan_out_to_every_element() {
+    let mut broadcaster = Broadcaster {
+        sinks: vec![Sink::new("a", 1), Sink::new("b", 2)],
+    };
+
+    assert_eq!(broadcaster.names(), vec!["a", "b"]);
+    assert_eq!(broadcaster.pending(), 3);
+    assert_eq!(broadcaster.lookup("b"), Some(2));
+    assert!(!broadcaster.is_idle());
+
+    broadcaster.flush();
+
+    assert_eq!(broadcaster.pending(), 0);
+    assert!(broadcaster.is_idle());
+    assert!(broadcaster.is_busy());
+    assert_eq!(broadcaster.lookup("c"), None);
+}
+
+#[test]
+fn should_short_circuit_on_first_error() {
+    let mut broadcaster = Broadcaster {
+        sinks: vec![Sink::new("a", 0), Sink::new("b", 0), Sink::new("c", 0)],
+    };
+    assert_eq!(broadcaster.check(), Ok(()));
+
+    broadcaster.sinks[1].broken = true;
+    broadcaster.sinks[2].broken = true;
+
+    assert_eq!(broadcaster.check(), Err("b".to_string()));
+}
+
+#[test]
+fn should_collect_results_until_first_error() {
+    let mut broadcaster = Broadcaster {
+        sinks: vec![Sink::new("a", 1), Sink::new("b", 2), Sink::new("c", 3)],
+    };
+    broadcaster.sinks[1].broken = true;
+
+    assert_eq!(broadcaster.drain(), Err("b".to_string()));
+    assert_eq!(broadcaster.pending(), 5);
+
+    broadcaster.sinks[1].broken = false;
+
+    assert_eq!(broadcaster.drain(), Ok(vec![0, 2, 3]));
+    assert_eq!(broadcaster.pending(), 0);
+}
+
+#[test]
+fn should_fan_out_to_array() {
+    let mut pair = Pair {
+        sinks: [Sink::new("a", 1), Sink::default()],
+    };
+
+    pair.flush();
+
+    assert_eq!(pair.pending(), 0);
+}
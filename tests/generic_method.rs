@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use forward_methods::fwd;
+
+struct Config {
+    raw: String,
+}
+
+struct Wrapper {
+    config: Config,
+}
+
+impl Config {
+    fn parse<T: FromStr>(&self) -> Option<T> {
+        self.raw.parse().ok()
+    }
+
+    fn describe<T: Into<String>>(&self, prefix: T) -> String {
+        format!("{}{}", prefix.into(), self.raw)
+    }
+
+    fn size_of<T, const N: usize>(&self) -> Option<usize> {
+        Some(std::mem::size_of::<T>() * N)
+    }
+}
+
+impl Wrapper {
+    fwd!(
+        fn parse<T: FromStr>(&self) -> Option<T>,
+        fn describe<T: Into<String>>(&self, prefix: T) -> String,
+        fn size_of<T, const N: usize>(&self) -> Option<usize>
+        to self.config
+    );
+}
+
+#[test]
+fn should_forward_generic_methods() {
+    let wrapper = Wrapper {
+        config: Config {
+            raw: "42".to_string(),
+        },
+    };
+
+    assert_eq!(wrapper.parse::<u32>(), Some(42));
+    assert_eq!(wrapper.parse::<bool>(), None);
+    assert_eq!(wrapper.describe("value: "), "value: 42");
+    assert_eq!(wrapper.size_of::<u16, 3>(), Some(6));
+}
@@ -0,0 +1,50 @@
+#![allow(clippy::needless_lifetimes)]
+
+use std::collections::HashMap;
+
+use forward_methods::fwd;
+
+struct Cache {
+    entries: HashMap<String, String>,
+}
+
+impl Cache {
+    fn get<'a>(&'a self, key: &'a str) -> Option<&'a String> {
+        self.entries.get(key)
+    }
+
+    fn get_mut<'a, 'k>(&'a mut self, key: &'k str) -> Option<&'a mut String> {
+        self.entries.get_mut(key)
+    }
+
+    fn first_key<'a>(&'a self) -> &'a str {
+        self.entries.keys().next().map_or("", String::as_str)
+    }
+}
+
+struct Wrapper {
+    cache: Cache,
+}
+
+impl Wrapper {
+    fwd!(
+        fn get<'a>(&'a self, key: &'a str) -> Option<&'a String>,
+        fn get_mut<'a, 'k>(&'a mut self, key: &'k str) -> Option<&'a mut String>,
+        fn first_key<'a>(&'a self) -> &'a str
+        to self.cache
+    );
+}
+
+#[test]
+fn should_forward_methods_with_lifetimes() {
+    let mut wrapper = Wrapper {
+        cache: Cache {
+            entries: HashMap::from([("key".to_string(), "value".to_string())]),
+        },
+    };
+
+    wrapper.get_mut("key").unwrap().push('!');
+
+    assert_eq!(wrapper.get("key").unwrap(), "value!");
+    assert_eq!(wrapper.first_key(), "key");
+}
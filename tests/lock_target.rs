@@ -0,0 +1,93 @@
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+use forward_methods::fwd;
+
+#[derive(Default)]
+struct Registry {
+    names: Vec<String>,
+}
+
+impl Registry {
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    fn register(&mut self, name: &str) {
+        self.names.push(name.to_string())
+    }
+
+    fn first(&self) -> Option<String> {
+        self.names.first().cloned()
+    }
+}
+
+#[derive(Clone, Default)]
+struct SharedRegistry {
+    inner: Arc<Mutex<Registry>>,
+}
+
+#[derive(Default)]
+struct ReadMostlyRegistry {
+    inner: RwLock<Registry>,
+}
+
+impl SharedRegistry {
+    fwd!(
+        fn len(&self) -> usize,
+        fn register(&mut self, name: &str),
+        fn first(&self) -> Option<String>
+        to lock self.inner
+    );
+    fwd!(fn recovered_len as len(&self) -> usize to lock(recover) self.inner);
+    fwd!(fn checked_len as len(&self) -> usize to lock(result) self.inner);
+}
+
+impl ReadMostlyRegistry {
+    fwd!(
+        fn len(&self) -> usize,
+        fn register(&mut self, name: &str)
+        to rwlock self.inner
+    );
+    fwd!(fn checked_first as first(&self) -> Option<String> to rwlock(result) self.inner);
+}
+
+#[test]
+fn should_forward_methods_through_mutex() {
+    let registry = SharedRegistry::default();
+    let other = registry.clone();
+
+    thread::spawn(move || other.register("worker"))
+        .join()
+        .unwrap();
+    registry.register("main");
+
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.first(), Some("worker".to_string()));
+}
+
+#[test]
+fn should_apply_poisoning_policy() {
+    let registry = SharedRegistry::default();
+    registry.register("main");
+
+    let other = registry.clone();
+    let _ = thread::spawn(move || {
+        let _guard = other.inner.lock().unwrap();
+        panic!("poison the lock")
+    })
+    .join();
+
+    assert_eq!(registry.recovered_len(), 1);
+    assert!(registry.checked_len().is_err());
+}
+
+#[test]
+fn should_forward_methods_through_rwlock() {
+    let registry = ReadMostlyRegistry::default();
+
+    registry.register("main");
+
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.checked_first().unwrap(), Some("main".to_string()));
+}
@@ -0,0 +1,55 @@
+use forward_methods::{fwd, Forward};
+
+use crate::stubs::{Message, Printer};
+
+mod stubs;
+
+struct Config(Message, (u8, Printer));
+
+struct State {
+    config: Config,
+}
+
+struct Outer {
+    state: State,
+}
+
+struct Pair((Message, Printer));
+
+impl Outer {
+    fwd!(fn get_message(&self) -> String, fn get_len(&self) -> usize to self.state.config.0);
+    fwd!(fn println(&self, msg: impl Into<String>) to self.state.config.1.1);
+}
+
+impl Pair {
+    fwd!(fn get_len(&self) -> usize to self.0.0);
+}
+
+#[derive(Forward)]
+struct Derived {
+    #[forward(fn get_len(&self) -> usize)]
+    message: Message,
+}
+
+#[test]
+fn should_forward_methods_to_nested_members() {
+    let outer = Outer {
+        state: State {
+            config: Config(Message("hello, world!".to_string()), (0, Printer)),
+        },
+    };
+
+    outer.println(format!("{}: {}", outer.get_message(), outer.get_len()));
+    assert_eq!(outer.get_len(), 13);
+}
+
+#[test]
+fn should_forward_methods_to_nested_tuple_members() {
+    let pair = Pair((Message("hello".to_string()), Printer));
+    let derived = Derived {
+        message: Message("hi".to_string()),
+    };
+
+    assert_eq!(pair.get_len(), 5);
+    assert_eq!(derived.get_len(), 2);
+}
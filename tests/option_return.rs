@@ -1,3 +1,5 @@
+#![allow(static_mut_refs)]
+
 use forward_methods::fwd;
 
 struct Mover;
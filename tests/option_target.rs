@@ -0,0 +1,75 @@
+use forward_methods::fwd;
+
+#[derive(Default)]
+struct Engine {
+    name: String,
+    runs: usize,
+}
+
+impl Engine {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn runs(&self) -> usize {
+        self.runs
+    }
+
+    fn run(&mut self) {
+        self.runs += 1
+    }
+
+    fn into_name(self) -> String {
+        self.name
+    }
+}
+
+#[derive(Default)]
+struct Car {
+    engine: Option<Engine>,
+}
+
+impl Car {
+    fwd!(
+        fn name(&self) -> String,
+        fn run(&mut self),
+        fn into_name(self) -> String
+        to self.engine?
+    );
+    fwd!(fn runs(&self) -> usize to self.engine? else default);
+    fwd!(fn engine_name as name(&self) -> String to self.engine? else panic("car has no engine"));
+}
+
+#[test]
+fn should_map_present_optional_target() {
+    let mut car = Car {
+        engine: Some(Engine {
+            name: "V8".to_string(),
+            runs: 0,
+        }),
+    };
+
+    car.run();
+
+    assert_eq!(car.name(), Some("V8".to_string()));
+    assert_eq!(car.runs(), 1);
+    assert_eq!(car.engine_name(), "V8");
+    assert_eq!(car.into_name(), Some("V8".to_string()));
+}
+
+#[test]
+fn should_skip_empty_optional_target() {
+    let mut car = Car::default();
+
+    car.run();
+
+    assert_eq!(car.name(), None);
+    assert_eq!(car.runs(), 0);
+    assert_eq!(car.into_name(), None);
+}
+
+#[test]
+#[should_panic(expected = "car has no engine")]
+fn should_panic_for_empty_optional_target() {
+    Car::default().engine_name();
+}
@@ -0,0 +1,43 @@
+use std::ops::Range;
+
+use forward_methods::fwd;
+
+struct Buffer([u8; 4]);
+
+struct Wrapper(Buffer);
+
+impl Buffer {
+    const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// # Safety
+    /// `range` must be in bounds.
+    unsafe fn get_unchecked(&self, range: Range<usize>) -> &[u8] {
+        unsafe { self.0.get_unchecked(range) }
+    }
+
+    extern "C" fn first(&self) -> u8 {
+        self.0[0]
+    }
+}
+
+impl Wrapper {
+    fwd!(
+        const fn len(&self) -> usize,
+        unsafe fn get_unchecked(&self, range: Range<usize>) -> &[u8],
+        extern "C" fn first(&self) -> u8
+        to self.0
+    );
+}
+
+const LEN: usize = Wrapper(Buffer([1, 2, 3, 4])).len();
+
+#[test]
+fn should_forward_qualified_methods() {
+    let wrapper = Wrapper(Buffer([1, 2, 3, 4]));
+
+    assert_eq!(LEN, 4);
+    assert_eq!(unsafe { wrapper.get_unchecked(1..3) }, &[2, 3]);
+    assert_eq!(wrapper.first(), 1);
+}
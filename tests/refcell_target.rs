@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+
+use forward_methods::fwd;
+
+#[derive(Default)]
+struct Counter {
+    value: usize,
+}
+
+struct Shared {
+    counter: RefCell<Counter>,
+}
+
+trait Count {
+    fn count(&self) -> usize;
+    fn bump(&mut self);
+}
+
+impl Counter {
+    fn get(&self) -> usize {
+        self.value
+    }
+
+    fn add(&mut self, n: usize) {
+        self.value += n
+    }
+}
+
+impl Count for Counter {
+    fn count(&self) -> usize {
+        self.value
+    }
+
+    fn bump(&mut self) {
+        self.value += 1
+    }
+}
+
+impl Shared {
+    fwd!(fn get(&self) -> usize, fn add(&mut self, n: usize) to borrow self.counter);
+    fwd!(
+        fn try_get as get(&self) -> usize,
+        fn try_add as add(&mut self, n: usize)
+        to try_borrow self.counter
+    );
+}
+
+fwd!(impl Count for Shared {
+    fn count(&self) -> usize,
+    fn bump(&mut self)
+} to borrow self.counter);
+
+#[test]
+fn should_forward_methods_through_refcell() {
+    let shared = Shared {
+        counter: RefCell::default(),
+    };
+
+    shared.add(2);
+    assert_eq!(shared.get(), 2);
+}
+
+#[test]
+fn should_forward_methods_through_refcell_with_try_borrow() {
+    let shared = Shared {
+        counter: RefCell::default(),
+    };
+
+    shared.try_add(3).unwrap();
+    assert_eq!(shared.try_get().unwrap(), 3);
+
+    let _guard = shared.counter.borrow();
+    assert!(shared.try_get().is_ok());
+    assert!(shared.try_add(1).is_err());
+}
+
+#[test]
+fn should_forward_trait_through_refcell() {
+    let mut shared = Shared {
+        counter: RefCell::default(),
+    };
+
+    shared.bump();
+    assert_eq!(Count::count(&shared), 1);
+}
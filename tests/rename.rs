@@ -0,0 +1,34 @@
+use forward_methods::fwd;
+
+struct Composite {
+    message: String,
+    items: Vec<u8>,
+}
+
+impl Composite {
+    fwd!(
+        fn message_len as len(&self) -> usize,
+        fn clear_message as clear(&mut self)
+        to self.message
+    );
+    fwd!(
+        fn items_len as len(&self) -> usize,
+        fn push_item as push(&mut self, item: u8)
+        to self.items
+    );
+}
+
+#[test]
+fn should_forward_renamed_methods() {
+    let mut cmp = Composite {
+        message: "hello".to_string(),
+        items: vec![1, 2],
+    };
+
+    cmp.push_item(3);
+    assert_eq!(cmp.message_len(), 5);
+    assert_eq!(cmp.items_len(), 3);
+
+    cmp.clear_message();
+    assert_eq!(cmp.message_len(), 0);
+}
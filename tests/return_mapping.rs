@@ -0,0 +1,67 @@
+use forward_methods::fwd;
+
+#[derive(Clone, Copy)]
+struct InnerId(u32);
+
+#[derive(Debug, PartialEq)]
+struct OuterId(u32);
+
+impl From<InnerId> for OuterId {
+    fn from(id: InnerId) -> Self {
+        OuterId(id.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Node(u32);
+
+impl From<&InnerId> for Node {
+    fn from(id: &InnerId) -> Self {
+        Node(id.0)
+    }
+}
+
+struct Graph {
+    ids: Vec<InnerId>,
+}
+
+impl Graph {
+    fn root(&self) -> InnerId {
+        self.ids[0]
+    }
+
+    fn nodes(&self) -> std::slice::Iter<'_, InnerId> {
+        self.ids.iter()
+    }
+
+    fn find(&self, id: u32) -> Option<&InnerId> {
+        self.ids.iter().find(|x| x.0 == id)
+    }
+}
+
+struct Tree {
+    graph: Graph,
+}
+
+impl Tree {
+    fwd!(
+        fn root(&self) -> OuterId via OuterId::from,
+        fn nodes(&self) -> Vec<Node> via |x| x.map(Node::from).collect(),
+        fn find(&self, id: u32) -> Option<Node> via |x| x.map(Node::from)
+        to self.graph
+    );
+}
+
+#[test]
+fn should_map_forwarded_return_values() {
+    let tree = Tree {
+        graph: Graph {
+            ids: vec![InnerId(1), InnerId(2)],
+        },
+    };
+
+    assert_eq!(tree.root(), OuterId(1));
+    assert_eq!(tree.nodes(), vec![Node(1), Node(2)]);
+    assert_eq!(tree.find(2), Some(Node(2)));
+    assert_eq!(tree.find(3), None);
+}
@@ -0,0 +1,78 @@
+use std::sync::{Mutex, MutexGuard};
+
+use forward_methods::fwd;
+
+struct Store {
+    name: String,
+    ids: Vec<u64>,
+    count: u32,
+    guarded: Mutex<u8>,
+}
+
+impl Store {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn label(&self) -> &str {
+        &self.name
+    }
+
+    fn count(&self) -> &u32 {
+        &self.count
+    }
+
+    fn small_count(&self) -> u32 {
+        self.count
+    }
+
+    fn ids(&self) -> std::slice::Iter<'_, u64> {
+        self.ids.iter()
+    }
+
+    fn guarded(&self) -> MutexGuard<'_, u8> {
+        self.guarded.lock().unwrap()
+    }
+}
+
+struct Service {
+    store: Store,
+}
+
+impl Service {
+    fwd!(
+        fn ids(&self) -> std::slice::Iter<'_, u64>,
+        fn guarded(&self) -> MutexGuard<'_, u8>,
+        #[fwd(ret = clone)]
+        fn name(&self) -> String,
+        #[fwd(ret = to_owned)]
+        fn label(&self) -> String,
+        #[fwd(ret = copy)]
+        fn count(&self) -> u32,
+        #[fwd(ret = into)]
+        fn small_count(&self) -> u64,
+        #[fwd(ret = auto)]
+        fn auto_name as name(&self) -> String
+        to self.store
+    );
+}
+
+#[test]
+fn should_forward_with_return_modes() {
+    let service = Service {
+        store: Store {
+            name: "store".to_string(),
+            ids: vec![1, 2],
+            count: 7,
+            guarded: Mutex::new(3),
+        },
+    };
+
+    assert_eq!(service.ids().sum::<u64>(), 3);
+    assert_eq!(*service.guarded(), 3);
+    assert_eq!(service.name(), "store");
+    assert_eq!(service.label(), "store");
+    assert_eq!(service.count(), 7);
+    assert_eq!(service.small_count(), 7u64);
+    assert_eq!(service.auto_name(), "store");
+}
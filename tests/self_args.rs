@@ -0,0 +1,61 @@
+use forward_methods::{fwd, Forward};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Counter {
+    hits: u32,
+}
+
+impl Counter {
+    fn merge(&mut self, other: &Counter) {
+        self.hits += other.hits
+    }
+
+    fn absorb(&mut self, other: Counter) {
+        self.hits += other.hits
+    }
+
+    fn drain(&mut self, other: &mut Counter) {
+        self.hits += other.hits;
+        other.hits = 0
+    }
+
+    fn max_with(&self, other: Option<&Counter>) -> u32 {
+        other.map_or(self.hits, |x| self.hits.max(x.hits))
+    }
+}
+
+#[derive(Debug, Default, Forward)]
+struct Stats {
+    #[forward(impl PartialEq for Stats { fn eq(&self, other: &Self) -> bool })]
+    counter: Counter,
+}
+
+impl Stats {
+    fwd!(
+        fn merge(&mut self, other: &Self),
+        fn absorb(&mut self, other: Self),
+        fn drain(&mut self, other: &mut Self),
+        fn max_with(&self, other: Option<&Self>) -> u32
+        to self.counter
+    );
+}
+
+#[test]
+fn should_project_self_parameters_onto_target() {
+    let mut stats = Stats::default();
+    let mut other = Stats {
+        counter: Counter { hits: 2 },
+    };
+
+    stats.merge(&other);
+    stats.absorb(Stats {
+        counter: Counter { hits: 3 },
+    });
+    stats.drain(&mut other);
+
+    assert_eq!(stats.counter.hits, 7);
+    assert_eq!(other.counter.hits, 0);
+    assert_eq!(stats.max_with(Some(&other)), 7);
+    assert_eq!(stats.max_with(None), 7);
+    assert_ne!(stats, other);
+}
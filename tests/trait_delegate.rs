@@ -0,0 +1,125 @@
+use std::fmt::{self, Display, Formatter};
+
+use forward_methods::{fwd, Forward};
+
+trait Counter {
+    fn count(&self) -> usize;
+    fn add(&mut self, n: usize);
+    fn reset(&mut self) -> usize;
+}
+
+#[derive(Default)]
+struct Inner {
+    value: usize,
+}
+
+struct Outer(Inner);
+
+struct Generic<T> {
+    inner: T,
+}
+
+#[derive(Forward)]
+struct Derived {
+    #[forward(impl Display for Derived)]
+    #[forward(fn count(&self) -> usize)]
+    inner: Inner,
+}
+
+impl Display for Inner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "inner({})", self.value)
+    }
+}
+
+impl Counter for Inner {
+    fn count(&self) -> usize {
+        self.value
+    }
+    fn add(&mut self, n: usize) {
+        self.value += n
+    }
+    fn reset(&mut self) -> usize {
+        std::mem::take(&mut self.value)
+    }
+}
+
+impl Inner {
+    fn count(&self) -> usize {
+        self.value * 10
+    }
+}
+
+fwd!(impl Display for Outer to self.0);
+fwd!(impl fmt::Debug for Outer { fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result } to self.0);
+fwd!(impl Counter for Outer {
+    fn count(&self) -> usize,
+    fn add(&mut self, n: usize),
+    fn reset(&mut self) -> usize
+} to self.0);
+
+fwd!(impl<T> Display for Generic<T> where T: Display to self.inner);
+
+struct Bytes(std::vec::IntoIter<u8>);
+
+fwd!(impl Iterator for Bytes {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8>
+} to self.0);
+
+struct Wrapper(String);
+
+fwd!(impl std::ops::Deref for Wrapper {
+    type Target = str;
+    fn deref(&self) -> &str
+} to self.0);
+
+impl fmt::Debug for Inner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Inner {{ value: {} }}", self.value)
+    }
+}
+
+#[test]
+fn should_forward_formatting_traits() {
+    let outer = Outer(Inner { value: 4 });
+
+    assert_eq!(outer.to_string(), "inner(4)");
+    assert_eq!(format!("{:?}", outer), "Inner { value: 4 }");
+}
+
+#[test]
+fn should_forward_trait_methods() {
+    let mut outer = Outer(Inner::default());
+
+    outer.add(3);
+    assert_eq!(Counter::count(&outer), 3);
+    assert_eq!(outer.reset(), 3);
+    assert_eq!(Counter::count(&outer), 0);
+}
+
+#[test]
+fn should_forward_generic_trait() {
+    let outer = Generic { inner: 42 };
+
+    assert_eq!(outer.to_string(), "42")
+}
+
+#[test]
+fn should_forward_traits_with_associated_types() {
+    let bytes = Bytes(vec![1, 2, 3].into_iter());
+    let wrapper = Wrapper("wrapped".to_string());
+
+    assert_eq!(bytes.sum::<u8>(), 6);
+    assert_eq!(wrapper.len(), 7);
+}
+
+#[test]
+fn should_forward_derived_trait() {
+    let derived = Derived {
+        inner: Inner { value: 2 },
+    };
+
+    assert_eq!(derived.to_string(), "inner(2)");
+    assert_eq!(derived.count(), 20);
+}
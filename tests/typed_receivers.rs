@@ -0,0 +1,73 @@
+use std::pin::Pin;
+use std::rc::Rc;
+
+use forward_methods::fwd;
+
+#[derive(Debug, Default)]
+struct Buffer {
+    data: String,
+}
+
+impl Buffer {
+    fn into_data(self) -> String {
+        self.data
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn push(self: Pin<&mut Self>, ch: char) {
+        self.get_mut().data.push(ch)
+    }
+
+    fn first(self: Pin<&Self>) -> Option<char> {
+        self.get_ref().data.chars().next()
+    }
+}
+
+trait IntoLen {
+    fn into_len(self: Box<Self>) -> usize;
+}
+
+impl IntoLen for Buffer {
+    fn into_len(self: Box<Self>) -> usize {
+        self.data.len()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Editor {
+    buffer: Buffer,
+}
+
+impl Editor {
+    fwd!(
+        #[allow(clippy::boxed_local)]
+        fn into_data(self: Box<Self>) -> String,
+        fn len(self: Rc<Self>) -> usize
+        to self.buffer
+    );
+
+    // Safety: 'buffer' is structurally pinned, 'Editor' never moves it out of a pinned reference
+    fwd!(
+        fn push(self: Pin<&mut Self>, ch: char),
+        fn first(self: Pin<&Self>) -> Option<char>
+        to unsafe pin self.buffer
+    );
+}
+
+fwd!(impl IntoLen for Editor { fn into_len(self: Box<Self>) -> usize } to self.buffer);
+
+#[test]
+fn should_forward_typed_receivers() {
+    let mut editor = Editor::default();
+
+    Pin::new(&mut editor).push('a');
+    Pin::new(&mut editor).push('b');
+
+    assert_eq!(Pin::new(&editor).first(), Some('a'));
+    assert_eq!(Rc::new(Editor::default()).len(), 0);
+    assert_eq!(Box::new(Editor::default()).into_len(), 0);
+    assert_eq!(Box::new(editor).into_data(), "ab");
+}
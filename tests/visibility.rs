@@ -0,0 +1,55 @@
+mod facade {
+    use forward_methods::{fwd, Forward};
+
+    pub struct Store {
+        items: Vec<u8>,
+    }
+
+    #[derive(Forward)]
+    pub struct DerivedStore {
+        #[forward(pub fn len(&self) -> usize, pub(crate) fn is_empty(&self) -> bool)]
+        items: Vec<u8>,
+    }
+
+    impl Store {
+        pub fn new(items: Vec<u8>) -> Self {
+            Store { items }
+        }
+
+        fwd!(
+            pub fn len(&self) -> usize,
+            pub(crate) fn is_empty(&self) -> bool,
+            pub(super) fn first(&self) -> Option<&u8>,
+            fn last(&self) -> Option<&u8>
+            to self.items
+        );
+
+        pub fn last_or_zero(&self) -> u8 {
+            self.last().copied().unwrap_or(0)
+        }
+    }
+
+    impl DerivedStore {
+        pub fn new(items: Vec<u8>) -> Self {
+            DerivedStore { items }
+        }
+    }
+}
+
+#[test]
+fn should_forward_methods_with_mixed_visibility() {
+    let store = facade::Store::new(vec![1, 2, 3]);
+
+    assert_eq!(store.len(), 3);
+    assert!(!store.is_empty());
+    assert_eq!(store.first(), Some(&1));
+    assert_eq!(store.last_or_zero(), 3);
+}
+
+#[test]
+fn should_derive_methods_with_mixed_visibility() {
+    let store = facade::DerivedStore::new(vec![]);
+
+    assert_eq!(store.len(), 0);
+    assert!(store.is_empty());
+}
@@ -0,0 +1,42 @@
+use forward_methods::fwd;
+
+struct Items(Vec<u8>);
+
+struct Wrapper {
+    items: Items,
+}
+
+impl Items {
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&u8) -> bool,
+    {
+        self.0.iter().position(f)
+    }
+
+    fn into_sorted(mut self) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        self.0.sort();
+        self.0
+    }
+}
+
+impl Wrapper {
+    fwd!(
+        fn position<F>(&self, f: F) -> Option<usize> where F: FnMut(&u8) -> bool,
+        fn into_sorted(self) -> Vec<u8> where Self: Sized
+        to self.items
+    );
+}
+
+#[test]
+fn should_forward_methods_with_where_clauses() {
+    let wrapper = Wrapper {
+        items: Items(vec![3, 1, 2]),
+    };
+
+    assert_eq!(wrapper.position(|x| *x == 2), Some(2));
+    assert_eq!(wrapper.into_sorted(), vec![1, 2, 3]);
+}